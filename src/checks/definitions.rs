@@ -13,7 +13,7 @@ pub fn all_checks() -> Vec<Check> {
         Check {
             id: "pipeline_green".into(),
             name: "Pipeline vert sur main".into(),
-            description: "Le dernier run du workflow sur main est en succès".into(),
+            description: "Le dernier run push/pull_request terminé sur main est en succès".into(),
             category: CheckCategory::Pipeline,
         },
         Check {
@@ -46,6 +46,96 @@ pub fn all_checks() -> Vec<Check> {
             description: "Des notifications sont envoyées sur Discord ou Slack en cas de succès ou d'échec du pipeline".into(),
             category: CheckCategory::Pipeline,
         },
+        Check {
+            id: "frozen_install".into(),
+            name: "Installations reproductibles (lockfile)".into(),
+            description: "Les dépendances sont installées en mode verrouillé (npm ci, --frozen-lockfile, --locked) sans réécrire le lockfile".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "matrix_include_exclude".into(),
+            name: "Matrice include/exclude cohérente".into(),
+            description: "Les matrices utilisant include/exclude produisent un nombre de combinaisons raisonnable (ni une seule, ni une explosion)".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "scheduled_workflow".into(),
+            name: "Workflow planifié (cron)".into(),
+            description: "Au moins un workflow est déclenché périodiquement via 'on: schedule' (audits, scans, maintenance)".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "artifact_upload".into(),
+            name: "Artefacts de build conservés".into(),
+            description: "Le pipeline persiste ses artefacts de build avec actions/upload-artifact (et les réutilise via download-artifact)".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "merge_queue_concurrency".into(),
+            name: "Concurrence sur la merge queue".into(),
+            description: "Les workflows déclenchés par merge_group définissent un groupe de concurrence propre à la file de merge pour éviter les runs spéculatifs en double".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "tool_caching".into(),
+            name: "Cache des outils".into(),
+            description: "Les outils et binaires téléchargés en CI sont mis en cache entre les runs".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "scheduled_concurrency".into(),
+            name: "Concurrence des jobs planifiés".into(),
+            description: "Les workflows cron ont un groupe de concurrence empêchant les runs de se chevaucher".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "fetch_tags_when_needed".into(),
+            name: "Tags récupérés pour le versionnement".into(),
+            description: "Les workflows versionnés par tags git récupèrent les tags (fetch-tags / fetch-depth: 0)".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "path_filters".into(),
+            name: "Filtres de chemins sur les triggers".into(),
+            description: "Les workflows push / pull_request utilisent paths ou paths-ignore pour éviter les runs inutiles".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "matrix_fail_fast".into(),
+            name: "fail-fast désactivé dans les matrices".into(),
+            description: "Les matrices déclarent fail-fast: false pour voir tous les échecs".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "manual_trigger".into(),
+            name: "Déclenchement manuel documenté".into(),
+            description: "workflow_dispatch disponible, idéalement avec des inputs déclarés".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "ci_on_pr".into(),
+            name: "CI déclenchée sur les pull requests".into(),
+            description: "Au moins un workflow se déclenche sur pull_request, pas seulement sur push".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "cache_effective".into(),
+            name: "Cache adapté à la stack".into(),
+            description: "Le cache CI correspond aux langages du projet (~/.cargo pour Rust, ~/.npm pour Node.js…)".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "workflow_valid_yaml".into(),
+            name: "Workflows YAML valides".into(),
+            description: "Chaque workflow est un YAML valide déclarant 'on:' et 'jobs:'".into(),
+            category: CheckCategory::Pipeline,
+        },
+        Check {
+            id: "runner_maintained".into(),
+            name: "Images de runner maintenues".into(),
+            description: "Aucun job ne tourne sur une image de runner retirée (ubuntu-18.04, windows-2016…)".into(),
+            category: CheckCategory::Pipeline,
+        },
         // ── Qualité & Tests ──
         Check {
             id: "tests_exist".into(),
@@ -77,6 +167,42 @@ pub fn all_checks() -> Vec<Check> {
             description: "Un outil d'analyse qualité (SonarCloud, CodeClimate, Codacy) est intégré dans la CI".into(),
             category: CheckCategory::QualiteTests,
         },
+        Check {
+            id: "msrv_tested".into(),
+            name: "Version minimale testée (MSRV)".into(),
+            description: "La version minimale déclarée (rust-version, requires-python, engines.node) figure dans la matrice de tests".into(),
+            category: CheckCategory::QualiteTests,
+        },
+        Check {
+            id: "test_files_present".into(),
+            name: "Fichiers de test réels".into(),
+            description: "Le dépôt contient de vrais fichiers de test (tests/, *_test.go, test_*.py, *.test.ts, #[cfg(test)]…), pas seulement une étape de test en CI".into(),
+            category: CheckCategory::QualiteTests,
+        },
+        Check {
+            id: "review_artifacts".into(),
+            name: "Rapports téléchargeables sur les PR".into(),
+            description: "Les workflows de PR publient des rapports utiles à la review (coverage, tests, captures)".into(),
+            category: CheckCategory::QualiteTests,
+        },
+        Check {
+            id: "flaky_runs".into(),
+            name: "Relances de runs (flaky)".into(),
+            description: "Peu de runs relancés (run_attempt > 1), signe de tests stables".into(),
+            category: CheckCategory::QualiteTests,
+        },
+        Check {
+            id: "test_reporting".into(),
+            name: "Résultats de tests publiés".into(),
+            description: "Rapport JUnit publié sur le run ou la PR (dorny/test-reporter, action-junit-report…)".into(),
+            category: CheckCategory::QualiteTests,
+        },
+        Check {
+            id: "coverage_gate".into(),
+            name: "Seuil de coverage bloquant".into(),
+            description: "La CI échoue sous un seuil de coverage (--fail-under, coverageThreshold, target Codecov…)".into(),
+            category: CheckCategory::QualiteTests,
+        },
         // ── Sécurité ──
         Check {
             id: "no_secrets_in_code".into(),
@@ -102,6 +228,90 @@ pub fn all_checks() -> Vec<Check> {
             description: "La branche main est protégée avec PR obligatoire".into(),
             category: CheckCategory::Securite,
         },
+        Check {
+            id: "scan_scheduling_separation".into(),
+            name: "Scans de sécurité séparés de la CI de PR".into(),
+            description: "Les scans de sécurité lourds tournent sur un planning ou un workflow dédié plutôt qu'à chaque pull request".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "sbom_generation".into(),
+            name: "Génération de SBOM".into(),
+            description: "Le pipeline produit une nomenclature logicielle (SBOM) avec syft, CycloneDX, SPDX ou anchore/sbom-action".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "codeql_analysis".into(),
+            name: "Analyse CodeQL".into(),
+            description: "Le dépôt exécute l'analyse statique CodeQL (github/codeql-action) sur ses langages".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "secret_scanning_ci".into(),
+            name: "Scan de secrets en CI".into(),
+            description: "Un scanner de secrets (gitleaks, trufflehog, ggshield) s'exécute dans les workflows".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "release_sbom_attached".into(),
+            name: "SBOM attaché aux releases".into(),
+            description: "Le SBOM généré est publié avec les releases (assets ou attestation)".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "signed_commits".into(),
+            name: "Commits signés (≥ 80%)".into(),
+            description: "Au moins 80% des commits récents sont signés et vérifiés par GitHub".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "enforce_admins".into(),
+            name: "Protection appliquée aux admins".into(),
+            description: "Les règles de protection de main s'appliquent aussi aux administrateurs".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "required_status_checks".into(),
+            name: "Status checks obligatoires".into(),
+            description: "Au moins un status check CI est requis avant de merger sur main".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "review_count".into(),
+            name: "Nombre de reviews requis".into(),
+            description: "Au moins une approbation est requise avant de merger sur main".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "required_contexts_resolve".into(),
+            name: "Status checks requis résolus".into(),
+            description: "Chaque status check requis par la protection de main est produit par un job de workflow".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "dependency_review".into(),
+            name: "Revue des dépendances sur les PR".into(),
+            description: "actions/dependency-review-action bloque les PR introduisant des dépendances vulnérables".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "codeowner_reviews".into(),
+            name: "Review des code owners requise".into(),
+            description: "La protection de branche exige l'approbation des CODEOWNERS".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "renovate_valid".into(),
+            name: "Configuration Renovate valide".into(),
+            description: "renovate.json est un JSON valide déclarant des presets ou des packageRules".into(),
+            category: CheckCategory::Securite,
+        },
+        Check {
+            id: "trusted_actions".into(),
+            name: "Actions de sources officielles".into(),
+            description: "La majorité des actions utilisées viennent d'éditeurs officiels (actions, docker, github, clouds)".into(),
+            category: CheckCategory::Securite,
+        },
         // ── Conteneurisation ──
         Check {
             id: "dockerfile_exists".into(),
@@ -121,6 +331,42 @@ pub fn all_checks() -> Vec<Check> {
             description: "L'image Docker est poussée sur GitHub Container Registry (ghcr.io)".into(),
             category: CheckCategory::Conteneurisation,
         },
+        Check {
+            id: "docker_nonroot".into(),
+            name: "Conteneur non-root".into(),
+            description: "Le Dockerfile définit un utilisateur non-root via l'instruction USER".into(),
+            category: CheckCategory::Conteneurisation,
+        },
+        Check {
+            id: "docker_pinned_base".into(),
+            name: "Image de base épinglée".into(),
+            description: "Chaque image FROM du Dockerfile est épinglée sur un tag précis ou un digest (pas de :latest)".into(),
+            category: CheckCategory::Conteneurisation,
+        },
+        Check {
+            id: "dockerignore_exists".into(),
+            name: ".dockerignore présent".into(),
+            description: "Un fichier .dockerignore accompagne le Dockerfile pour limiter le contexte de build".into(),
+            category: CheckCategory::Conteneurisation,
+        },
+        Check {
+            id: "image_signing".into(),
+            name: "Image signée (cosign)".into(),
+            description: "L'image publiée est signée avec cosign / sigstore pour garantir sa provenance".into(),
+            category: CheckCategory::Conteneurisation,
+        },
+        Check {
+            id: "multiarch_build".into(),
+            name: "Build Docker multi-architecture".into(),
+            description: "Les images sont construites pour plusieurs plateformes (linux/amd64 + linux/arm64)".into(),
+            category: CheckCategory::Conteneurisation,
+        },
+        Check {
+            id: "image_tag_strategy".into(),
+            name: "Stratégie de tags d'image".into(),
+            description: "Les images publiées sont taguées par version, tag git ou SHA, pas seulement latest".into(),
+            category: CheckCategory::Conteneurisation,
+        },
         // ── Déploiement ──
         Check {
             id: "auto_deploy".into(),
@@ -146,6 +392,36 @@ pub fn all_checks() -> Vec<Check> {
             description: "Le dépôt dispose d'un mécanisme de rollback (workflow dédié, workflow_dispatch, revert automatique)".into(),
             category: CheckCategory::Deploiement,
         },
+        Check {
+            id: "deployment_url_published".into(),
+            name: "URL de déploiement publiée".into(),
+            description: "Les jobs de déploiement exposent une URL d'environnement (environment.url) cliquable depuis GitHub".into(),
+            category: CheckCategory::Deploiement,
+        },
+        Check {
+            id: "no_hardcoded_hosts".into(),
+            name: "Pas d'hôtes de déploiement en dur".into(),
+            description: "Les cibles de déploiement (URL, IP, hôtes SSH) proviennent de secrets, variables ou environnements plutôt que d'être écrites en dur".into(),
+            category: CheckCategory::Deploiement,
+        },
+        Check {
+            id: "prod_wait_timer".into(),
+            name: "Délai d'attente avant la prod".into(),
+            description: "L'environnement de production a une règle wait_timer (fenêtre d'annulation)".into(),
+            category: CheckCategory::Deploiement,
+        },
+        Check {
+            id: "auto_rollback_on_failure".into(),
+            name: "Rollback automatique sur échec".into(),
+            description: "Un échec des tests smoke post-déploiement déclenche automatiquement un rollback".into(),
+            category: CheckCategory::Deploiement,
+        },
+        Check {
+            id: "environment_protection".into(),
+            name: "Environnements protégés".into(),
+            description: "Au moins un environnement de déploiement a des règles de protection (reviewers ou délai)".into(),
+            category: CheckCategory::Deploiement,
+        },
         // ── Bonnes Pratiques ──
         Check {
             id: "readme_exists".into(),
@@ -183,5 +459,95 @@ pub fn all_checks() -> Vec<Check> {
             description: "Au moins une release ou un tag GitHub existe pour versionner le projet".into(),
             category: CheckCategory::BonnesPratiques,
         },
+        Check {
+            id: "funding_configured".into(),
+            name: "Financement (FUNDING.yml)".into(),
+            description: "Un fichier .github/FUNDING.yml déclare les plateformes de sponsoring du projet".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "license_exists".into(),
+            name: "Licence présente".into(),
+            description: "Le dépôt déclare une licence (fichier LICENSE/COPYING ou licence détectée par GitHub)".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "lockfile_committed".into(),
+            name: "Lockfile versionné".into(),
+            description: "Un lockfile de dépendances (Cargo.lock, package-lock.json, poetry.lock, go.sum…) est commité pour des builds reproductibles".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "version_bump_automation".into(),
+            name: "Versionnement automatisé".into(),
+            description: "Le numéro de version est incrémenté automatiquement (bump-my-version, standard-version, release-please, cargo release…)".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "issue_automation".into(),
+            name: "Tri automatique des issues".into(),
+            description: "Un workflow réagit aux issues (on: issues) pour étiqueter, assigner ou accueillir les contributeurs".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "reproducible_devenv".into(),
+            name: "Environnement de dev reproductible".into(),
+            description: "Devcontainer, flake Nix ou Gitpod configuré pour reproduire l'environnement de la CI".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "linear_history".into(),
+            name: "Historique linéaire".into(),
+            description: "La protection de main impose un historique linéaire (pas de commits de merge)".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "issue_templates".into(),
+            name: "Templates d'issue et de PR".into(),
+            description: "Templates dans .github/ISSUE_TEMPLATE/ ou .github/PULL_REQUEST_TEMPLATE.md".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "changelog_format".into(),
+            name: "Changelog au format Keep a Changelog".into(),
+            description: "CHANGELOG.md structuré en sections [Unreleased], Added, Changed, Fixed…".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "stale_bot".into(),
+            name: "Bot de tri des issues inactives".into(),
+            description: "actions/stale dans un workflow ou configuration .github/stale.yml".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "dependabot_automerge".into(),
+            name: "Auto-merge Dependabot".into(),
+            description: "Les mises à jour Dependabot mineures/patch sont fusionnées automatiquement".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "editorconfig_exists".into(),
+            name: ".editorconfig présent".into(),
+            description: "Fichier .editorconfig pour un style d'édition cohérent".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "precommit_config".into(),
+            name: "Hooks pre-commit".into(),
+            description: "pre-commit, Husky ou Lefthook configuré pour vérifier le code avant chaque commit".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "commit_lint_ci".into(),
+            name: "Lint des messages de commit en CI".into(),
+            description: "commitlint ou lint du titre de PR appliqué dans les workflows".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
+        Check {
+            id: "readme_ci_badge".into(),
+            name: "Badge CI dans le README".into(),
+            description: "Le README affiche un badge de statut GitHub Actions".into(),
+            category: CheckCategory::BonnesPratiques,
+        },
     ]
 }
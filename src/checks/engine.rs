@@ -1,10 +1,39 @@
 use std::collections::HashMap;
+use std::future::Future;
 
-use crate::models::{CategoryScore, CheckCategory, CheckResult, ScoreReport};
-use crate::services::{GithubClient, RepoIdentifier};
+use crate::models::{CategoryScore, Check, CheckCategory, CheckResult, ScoreReport};
+use crate::services::{ApiError, GithubClient, RepoIdentifier, RepoMetadata};
 
-use super::definitions::all_checks;
 use super::runner::CheckRunner;
+use super::selection::CheckSelection;
+
+/// Progress reporter invoked after each check: (checks done, total, check name).
+/// A plain closure keeps the engine UI-agnostic; the app forwards it to a Yew
+/// `Callback` to update its state.
+pub type Progress<'a> = &'a dyn Fn(usize, usize, &str);
+
+/// GitHub calls the engine makes around the checks. A seam so the analysis
+/// steps can run against a fake repository in native tests, where the real
+/// client (fetch API) is unavailable.
+trait EngineApi {
+    fn has_token(&self) -> bool;
+    async fn fetch_repo_metadata(&self, repo: &RepoIdentifier) -> Result<RepoMetadata, ApiError>;
+    async fn fetch_languages(&self, repo: &RepoIdentifier) -> Result<Vec<(String, u64)>, ApiError>;
+}
+
+impl EngineApi for GithubClient {
+    fn has_token(&self) -> bool {
+        GithubClient::has_token(self)
+    }
+
+    async fn fetch_repo_metadata(&self, repo: &RepoIdentifier) -> Result<RepoMetadata, ApiError> {
+        GithubClient::fetch_repo_metadata(self, repo).await
+    }
+
+    async fn fetch_languages(&self, repo: &RepoIdentifier) -> Result<Vec<(String, u64)>, ApiError> {
+        GithubClient::fetch_languages(self, repo).await
+    }
+}
 
 /// Orchestrates all checks and produces a ScoreReport
 pub struct CheckEngine {
@@ -17,82 +46,384 @@ impl CheckEngine {
     }
 
     /// Run all checks against a repository and return a full report
-    pub async fn analyze(&self, repo: &RepoIdentifier) -> Result<ScoreReport, String> {
-        // Verify repo exists
-        self.client
-            .fetch_repo_metadata(repo)
+    pub async fn analyze(
+        &self,
+        repo: &RepoIdentifier,
+        on_progress: Option<Progress<'_>>,
+    ) -> Result<ScoreReport, String> {
+        self.analyze_with(repo, &CheckSelection::All, on_progress)
             .await
-            .map_err(|e| format!("Impossible d'accéder au repo : {}", e))?;
+    }
 
-        let checks = all_checks();
-        let runner = CheckRunner::new(&self.client, repo);
+    /// Run the selected checks against a repository and return a report
+    /// covering only their categories
+    pub async fn analyze_with(
+        &self,
+        repo: &RepoIdentifier,
+        selection: &CheckSelection,
+        on_progress: Option<Progress<'_>>,
+    ) -> Result<ScoreReport, String> {
+        let (repo, primary_language) = resolve_target(&self.client, repo).await?;
+        let repo = &repo;
 
-        let mut results: Vec<CheckResult> = Vec::new();
-        for check in &checks {
-            let result = runner.run_check(check).await;
-            results.push(result);
-        }
+        let checks = selection.checks();
+        let runner = CheckRunner::new(&self.client, repo, primary_language.as_deref());
+
+        let results = run_checks(&checks, |check| runner.run_check(check), on_progress).await;
+
+        let analyzed_at = js_sys::Date::new_0()
+            .to_iso_string()
+            .as_string()
+            .unwrap_or_default();
+        let mut report = build_report(repo.full_name(), results, analyzed_at);
+        report.primary_language = primary_language;
+        report.branch = repo.branch.clone();
+        Ok(report)
+    }
+}
+
+/// Checks that the repository is readable and resolves what the checks run
+/// against: the repository pinned to a branch, and its primary language
+async fn resolve_target(
+    api: &impl EngineApi,
+    repo: &RepoIdentifier,
+) -> Result<(RepoIdentifier, Option<String>), String> {
+    let metadata = api
+        .fetch_repo_metadata(repo)
+        .await
+        .map_err(|e| repo_access_error(&e, api.has_token()))?;
+
+    // Branch protection needs a real branch name, so pin the default one
+    let repo = RepoIdentifier {
+        branch: Some(repo.branch.clone().unwrap_or(metadata.default_branch)),
+        ..repo.clone()
+    };
+
+    // Fetched once for the report and the language-aware checks;
+    // informative only: a failure must not abort the analysis
+    let primary_language = api
+        .fetch_languages(&repo)
+        .await
+        .ok()
+        .and_then(|languages| languages.into_iter().next())
+        .map(|(language, _)| language);
+
+    Ok((repo, primary_language))
+}
 
-        // Group results by category
-        let mut grouped: HashMap<CheckCategory, Vec<CheckResult>> = HashMap::new();
-        for result in results {
-            grouped
-                .entry(result.check.category.clone())
-                .or_default()
-                .push(result);
+/// Explains why the repository metadata could not be read. Without a token a
+/// private repository answers 404 like a missing one: the analysis stops
+/// there rather than reporting dozens of misleading failed checks.
+fn repo_access_error(error: &ApiError, has_token: bool) -> String {
+    match (error.status, has_token) {
+        (404, false) => "Dépôt introuvable ou privé : vérifiez l'URL, ou fournissez un token GitHub (scope « repo ») pour analyser un dépôt privé.".to_string(),
+        (404, true) => "Dépôt introuvable : vérifiez l'URL et que le token a accès à ce dépôt.".to_string(),
+        (403, false) => "Accès refusé par l'API GitHub (quota anonyme probablement atteint) : fournissez un token GitHub.".to_string(),
+        _ => format!("Impossible d'accéder au repo : {}", error),
+    }
+}
+
+/// Runs the checks one after another, reporting progress after each of them
+async fn run_checks<'c, F, Fut>(
+    checks: &'c [Check],
+    mut run: F,
+    on_progress: Option<Progress<'_>>,
+) -> Vec<CheckResult>
+where
+    F: FnMut(&'c Check) -> Fut,
+    Fut: Future<Output = CheckResult>,
+{
+    let total = checks.len();
+    let mut results = Vec::with_capacity(total);
+    for (i, check) in checks.iter().enumerate() {
+        results.push(run(check).await);
+        if let Some(report_progress) = on_progress {
+            report_progress(i + 1, total, &check.name);
         }
+    }
+    results
+}
 
-        // Build category scores — ordered to match the UI presentation
-        let category_order = [
-            CheckCategory::Pipeline,
-            CheckCategory::QualiteTests,
-            CheckCategory::Securite,
-            CheckCategory::Conteneurisation,
-            CheckCategory::Deploiement,
-            CheckCategory::BonnesPratiques,
-        ];
-
-        let mut categories = Vec::new();
-        let mut global_passed: u32 = 0;
-        let mut global_total: u32 = 0;
-
-        for cat in &category_order {
-            let cat_results = grouped.remove(cat).unwrap_or_default();
-            // Warnings count as passes; Skipped checks are excluded from the total
-            let passed: u32 = cat_results
-                .iter()
-                .filter(|r| {
-                    matches!(
-                        r.status,
-                        crate::models::CheckStatus::Passed | crate::models::CheckStatus::Warning
-                    )
-                })
-                .count() as u32;
-            let total: u32 = cat_results
+/// Groups check results by category and computes the category and global
+/// scores of the report
+fn build_report(repository: String, results: Vec<CheckResult>, analyzed_at: String) -> ScoreReport {
+    // Group results by category
+    let mut grouped: HashMap<CheckCategory, Vec<CheckResult>> = HashMap::new();
+    for result in results {
+        grouped
+            .entry(result.check.category.clone())
+            .or_default()
+            .push(result);
+    }
+
+    // Build category scores — ordered to match the UI presentation
+    let category_order = [
+        CheckCategory::Pipeline,
+        CheckCategory::QualiteTests,
+        CheckCategory::Securite,
+        CheckCategory::Conteneurisation,
+        CheckCategory::Deploiement,
+        CheckCategory::BonnesPratiques,
+    ];
+
+    let mut categories = Vec::new();
+    let mut global_passed: u32 = 0;
+    let mut global_total: u32 = 0;
+
+    for cat in &category_order {
+        // Categories left out by a check selection are not reported
+        let Some(cat_results) = grouped.remove(cat) else {
+            continue;
+        };
+        let (passed, total) = CategoryScore::tally(&cat_results);
+
+        global_passed += passed;
+        global_total += total;
+
+        categories.push(CategoryScore {
+            category: cat.clone(),
+            passed,
+            total,
+            results: cat_results,
+        });
+    }
+
+    ScoreReport {
+        repository,
+        primary_language: None,
+        branch: None,
+        passed: global_passed,
+        total: global_total,
+        categories,
+        analyzed_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::all_checks;
+    use crate::models::CheckStatus;
+
+    /// Canned runner output: one result per check, with a mix of outcomes
+    fn mocked_results() -> Vec<CheckResult> {
+        all_checks()
+            .into_iter()
+            .enumerate()
+            .map(|(i, check)| match i % 4 {
+                0 => CheckResult::passed(check, "ok"),
+                1 => CheckResult::warning(check, "partiel", "à améliorer"),
+                2 => CheckResult::failed(check, "ko", "à corriger"),
+                _ => CheckResult::skipped(check, "non applicable"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_build_report_scores_every_category() {
+        let results = mocked_results();
+        let evaluated = results
+            .iter()
+            .filter(|r| r.status != CheckStatus::Skipped)
+            .count() as u32;
+        let passing = results
+            .iter()
+            .filter(|r| matches!(r.status, CheckStatus::Passed | CheckStatus::Warning))
+            .count() as u32;
+
+        let report = build_report(
+            "owner/repo".to_string(),
+            results,
+            "2024-01-01T00:00:00.000Z".to_string(),
+        );
+
+        assert_eq!(report.repository, "owner/repo");
+        assert_eq!(report.categories.len(), 6);
+        assert_eq!(report.total, evaluated);
+        assert_eq!(report.passed, passing);
+        assert_eq!(
+            report
+                .categories
                 .iter()
-                .filter(|r| !matches!(r.status, crate::models::CheckStatus::Skipped))
-                .count() as u32;
-
-            global_passed += passed;
-            global_total += total;
-
-            categories.push(CategoryScore {
-                category: cat.clone(),
-                passed,
-                total,
-                results: cat_results,
-            });
+                .map(|c| c.results.len())
+                .sum::<usize>(),
+            all_checks().len()
+        );
+        assert_eq!(
+            report.categories.iter().map(|c| c.total).sum::<u32>(),
+            report.total
+        );
+    }
+
+    #[test]
+    fn test_run_checks_reports_progress_once_per_check() {
+        use std::cell::RefCell;
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        let checks = CheckSelection::minimal().checks();
+        let calls = RefCell::new(Vec::new());
+        let on_progress = |done: usize, total: usize, name: &str| {
+            calls.borrow_mut().push((done, total, name.to_string()));
+        };
+
+        // The mocked runner never awaits, so a single poll completes the run
+        let future = pin!(run_checks(
+            &checks,
+            |check| std::future::ready(CheckResult::passed(check.clone(), "ok")),
+            Some(&on_progress),
+        ));
+        let Poll::Ready(results) = future.poll(&mut Context::from_waker(Waker::noop())) else {
+            panic!("mocked checks should complete immediately");
+        };
+
+        let calls = calls.take();
+        assert_eq!(results.len(), checks.len());
+        assert_eq!(calls.len(), checks.len());
+        for (i, (done, total, name)) in calls.iter().enumerate() {
+            assert_eq!(*done, i + 1);
+            assert_eq!(*total, checks.len());
+            assert_eq!(name, &checks[i].name);
+        }
+    }
+
+    /// Fake GitHub API answering from canned data, without any network call
+    struct FakeApi {
+        metadata: Result<RepoMetadata, ApiError>,
+        languages: Result<Vec<(String, u64)>, ApiError>,
+    }
+
+    impl EngineApi for FakeApi {
+        fn has_token(&self) -> bool {
+            false
+        }
+
+        async fn fetch_repo_metadata(&self, _: &RepoIdentifier) -> Result<RepoMetadata, ApiError> {
+            self.metadata.clone()
+        }
+
+        async fn fetch_languages(
+            &self,
+            _: &RepoIdentifier,
+        ) -> Result<Vec<(String, u64)>, ApiError> {
+            self.languages.clone()
+        }
+    }
+
+    /// Polls a future that never awaits anything pending (fake API, canned runner)
+    fn complete<F: Future>(future: F) -> F::Output {
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("fake analysis should complete immediately"),
         }
+    }
+
+    fn fake_repo_api() -> FakeApi {
+        FakeApi {
+            metadata: Ok(serde_json::from_str(
+                r#"{ "name": "repo", "full_name": "owner/repo", "default_branch": "develop", "private": false }"#,
+            )
+            .unwrap()),
+            languages: Ok(vec![("Rust".into(), 9000), ("Shell".into(), 120)]),
+        }
+    }
+
+    #[test]
+    fn test_analysis_against_fake_api() {
+        let api = fake_repo_api();
+        let repo = GithubClient::parse_repo_url("owner/repo").unwrap();
+
+        let (repo, language) = complete(resolve_target(&api, &repo)).unwrap();
+        assert_eq!(repo.branch.as_deref(), Some("develop"));
+        assert_eq!(language.as_deref(), Some("Rust"));
+
+        // Canned runner standing in for the checks, which need the real client
+        let checks = CheckSelection::minimal().checks();
+        let results = complete(run_checks(
+            &checks,
+            |check| std::future::ready(CheckResult::passed(check.clone(), "ok")),
+            None,
+        ));
+        let report = build_report(repo.full_name(), results, String::new());
+        assert_eq!(report.repository, "owner/repo");
+        assert_eq!(report.total as usize, checks.len());
+        assert_eq!(report.passed, report.total);
+    }
+
+    #[test]
+    fn test_analysis_keeps_requested_branch_and_tolerates_language_errors() {
+        let api = FakeApi {
+            languages: Err(ApiError {
+                status: 500,
+                message: "boom".into(),
+            }),
+            ..fake_repo_api()
+        };
+        let mut repo = GithubClient::parse_repo_url("owner/repo").unwrap();
+        repo.branch = Some("feature/x".into());
+
+        let (repo, language) = complete(resolve_target(&api, &repo)).unwrap();
+        assert_eq!(repo.branch.as_deref(), Some("feature/x"));
+        assert_eq!(language, None);
+    }
+
+    #[test]
+    fn test_analysis_stops_on_unreadable_repo() {
+        let api = FakeApi {
+            metadata: Err(ApiError {
+                status: 404,
+                message: "Not Found".into(),
+            }),
+            ..fake_repo_api()
+        };
+        let repo = GithubClient::parse_repo_url("owner/private").unwrap();
+
+        let error = complete(resolve_target(&api, &repo)).unwrap_err();
+        assert!(error.contains("privé"));
+    }
+
+    #[test]
+    fn test_private_repo_without_token_asks_for_one() {
+        let not_found = ApiError {
+            status: 404,
+            message: "Not Found".into(),
+        };
+        let message = repo_access_error(&not_found, false);
+        assert!(message.contains("privé"));
+        assert!(message.contains("token"));
+
+        let message = repo_access_error(&not_found, true);
+        assert!(!message.contains("privé"));
+
+        let server_error = ApiError {
+            status: 500,
+            message: "boom".into(),
+        };
+        assert!(repo_access_error(&server_error, false).starts_with("Impossible d'accéder"));
+    }
+
+    #[test]
+    fn test_build_report_with_category_selection() {
+        let selection = CheckSelection::Categories(vec![CheckCategory::Conteneurisation]);
+        let results: Vec<CheckResult> = selection
+            .checks()
+            .into_iter()
+            .map(|check| CheckResult::passed(check, "ok"))
+            .collect();
+
+        let report = build_report("owner/repo".to_string(), results, String::new());
 
-        Ok(ScoreReport {
-            repository: repo.full_name(),
-            passed: global_passed,
-            total: global_total,
-            categories,
-            analyzed_at: js_sys::Date::new_0()
-                .to_iso_string()
-                .as_string()
-                .unwrap_or_default(),
-        })
+        assert_eq!(report.categories.len(), 1);
+        assert_eq!(
+            report.categories[0].category,
+            CheckCategory::Conteneurisation
+        );
+        assert!(report.categories[0]
+            .results
+            .iter()
+            .all(|r| r.check.category == CheckCategory::Conteneurisation));
     }
 }
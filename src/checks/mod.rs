@@ -1,6 +1,35 @@
 mod definitions;
 mod engine;
 mod runner;
+mod selection;
+mod workflow;
 
 pub use definitions::all_checks;
-pub use engine::CheckEngine;
+pub use engine::{CheckEngine, Progress};
+pub use selection::CheckSelection;
+
+use crate::models::{Check, CheckResult, ScoreReport};
+use crate::services::{GithubClient, RepoIdentifier};
+
+/// Runs the full analysis of a repository outside of any UI: the entry point
+/// for embedding the checker in a CLI or another frontend
+pub async fn run_analysis(
+    client: GithubClient,
+    repo: &RepoIdentifier,
+    on_progress: Option<Progress<'_>>,
+) -> Result<ScoreReport, String> {
+    CheckEngine::new(client).analyze(repo, on_progress).await
+}
+
+/// Re-runs a single check, e.g. after the user fixed what it reported.
+/// `primary_language` is the one recorded in the report being updated.
+pub async fn rerun_check(
+    client: GithubClient,
+    repo: &RepoIdentifier,
+    check: &Check,
+    primary_language: Option<&str>,
+) -> CheckResult {
+    runner::CheckRunner::new(&client, repo, primary_language)
+        .run_check(check)
+        .await
+}
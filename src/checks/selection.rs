@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use crate::models::{Check, CheckCategory};
+
+use super::definitions::all_checks;
+
+/// Checks of the `minimal` profile: the fundamentals every CI/CD setup needs
+const MINIMAL_CHECKS: [&str; 10] = [
+    "pipeline_exists",
+    "pipeline_green",
+    "ci_on_pr",
+    "tests_exist",
+    "lint_in_ci",
+    "dockerfile_exists",
+    "docker_build_ci",
+    "no_secrets_in_code",
+    "readme_exists",
+    "gitignore_exists",
+];
+
+/// Checks added by the `standard` profile on top of `minimal`
+const STANDARD_EXTRA_CHECKS: [&str; 7] = [
+    "security_scan",
+    "coverage_configured",
+    "dependabot_configured",
+    "branch_protection",
+    "pipeline_fast",
+    "multi_environment",
+    "auto_deploy",
+];
+
+/// Which checks an analysis runs
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckSelection {
+    /// Every check
+    All,
+    /// Only the checks of these categories
+    Categories(Vec<CheckCategory>),
+    /// Only the checks with these ids
+    Ids(HashSet<String>),
+}
+
+impl CheckSelection {
+    /// Fundamentals only: pipeline, tests, lint, Docker basics and secrets
+    pub fn minimal() -> Self {
+        Self::Ids(MINIMAL_CHECKS.iter().map(|id| id.to_string()).collect())
+    }
+
+    /// Fundamentals plus security scanning, coverage, branch protection and deployment
+    pub fn standard() -> Self {
+        Self::Ids(
+            MINIMAL_CHECKS
+                .iter()
+                .chain(STANDARD_EXTRA_CHECKS.iter())
+                .map(|id| id.to_string())
+                .collect(),
+        )
+    }
+
+    /// Every check of every category, from Pipeline to BonnesPratiques
+    pub fn strict() -> Self {
+        Self::All
+    }
+
+    /// Returns the profile with the given name (`minimal`, `standard`, `strict`)
+    pub fn profile(name: &str) -> Option<Self> {
+        match name {
+            "minimal" => Some(Self::minimal()),
+            "standard" => Some(Self::standard()),
+            "strict" => Some(Self::strict()),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the check is part of the selection
+    pub fn includes(&self, check: &Check) -> bool {
+        match self {
+            Self::All => true,
+            Self::Categories(categories) => categories.contains(&check.category),
+            Self::Ids(ids) => ids.contains(&check.id),
+        }
+    }
+
+    /// Returns the selected checks, in definition order
+    pub fn checks(&self) -> Vec<Check> {
+        all_checks()
+            .into_iter()
+            .filter(|check| self.includes(check))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_selection_only_selects_that_category() {
+        let selection = CheckSelection::Categories(vec![CheckCategory::Securite]);
+        let checks = selection.checks();
+        assert!(!checks.is_empty());
+        assert!(checks
+            .iter()
+            .all(|check| check.category == CheckCategory::Securite));
+    }
+
+    #[test]
+    fn test_profiles_reference_existing_checks() {
+        let minimal = CheckSelection::minimal().checks();
+        let standard = CheckSelection::standard().checks();
+        assert_eq!(minimal.len(), MINIMAL_CHECKS.len());
+        assert_eq!(
+            standard.len(),
+            MINIMAL_CHECKS.len() + STANDARD_EXTRA_CHECKS.len()
+        );
+        assert_eq!(CheckSelection::strict().checks().len(), all_checks().len());
+        assert!(CheckSelection::profile("unknown").is_none());
+    }
+}
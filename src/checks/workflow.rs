@@ -0,0 +1,420 @@
+use serde::{Deserialize, Deserializer};
+use serde_yaml::{Mapping, Value};
+
+/// GitHub Actions workflow, as far as the checks need it. Querying the parsed
+/// model rather than the raw text ignores comments and string contents.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Workflow {
+    #[serde(default)]
+    pub on: Triggers,
+    /// Jobs by id, in file order
+    #[serde(default, deserialize_with = "jobs_in_order")]
+    pub jobs: Vec<(String, Job)>,
+    /// Group name or `{ group, cancel-in-progress }` mapping
+    #[serde(default)]
+    pub concurrency: Option<Value>,
+}
+
+impl Workflow {
+    /// Returns true if the workflow or one of its jobs declares a concurrency group
+    pub fn has_concurrency(&self) -> bool {
+        self.concurrency.is_some() || self.jobs.iter().any(|(_, job)| job.concurrency.is_some())
+    }
+
+    /// Concurrency group names of the workflow and of its jobs
+    pub fn concurrency_groups(&self) -> Vec<String> {
+        std::iter::once(&self.concurrency)
+            .chain(self.jobs.iter().map(|(_, job)| &job.concurrency))
+            .flatten()
+            .filter_map(|concurrency| match concurrency {
+                Value::Mapping(settings) => settings.get("group").and_then(scalar_text),
+                value => scalar_text(value),
+            })
+            .collect()
+    }
+}
+
+/// Text of a string or number scalar
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+/// Deserializes the `jobs:` mapping without losing the order of the file
+fn jobs_in_order<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<(String, Job)>, D::Error> {
+    // A bare `jobs:` key is null rather than an empty mapping
+    let jobs = Option::<Mapping>::deserialize(deserializer)?.unwrap_or_default();
+    jobs.into_iter()
+        .map(|(id, job)| {
+            let id = id
+                .as_str()
+                .ok_or_else(|| serde::de::Error::custom("job id must be a string"))?
+                .to_string();
+            let job = Job::deserialize(job).map_err(serde::de::Error::custom)?;
+            Ok((id, job))
+        })
+        .collect()
+}
+
+/// The `on:` key in any of its forms
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Triggers {
+    /// `on: push`
+    Event(String),
+    /// `on: [push, pull_request]`
+    Events(Vec<String>),
+    /// `on:` followed by a block mapping of events and their filters
+    Detailed(Mapping),
+}
+
+impl Default for Triggers {
+    fn default() -> Self {
+        Triggers::Events(Vec::new())
+    }
+}
+
+impl Triggers {
+    /// Names of the triggering events
+    pub fn events(&self) -> Vec<&str> {
+        match self {
+            Triggers::Event(event) => vec![event.as_str()],
+            Triggers::Events(events) => events.iter().map(String::as_str).collect(),
+            Triggers::Detailed(events) => events.keys().filter_map(Value::as_str).collect(),
+        }
+    }
+
+    pub fn contains(&self, event: &str) -> bool {
+        self.events().contains(&event)
+    }
+}
+
+/// Reads a scalar as text: YAML types `name: 3.11` as a number and `run: 42`
+/// as an integer, which a plain `String` field would reject along with the
+/// whole workflow
+fn scalar_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    Ok(match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::String(text)) => Some(text),
+        Some(Value::Number(number)) => Some(number.to_string()),
+        Some(Value::Bool(flag)) => Some(flag.to_string()),
+        _ => None,
+    })
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Job {
+    #[serde(default, deserialize_with = "scalar_string")]
+    pub name: Option<String>,
+    /// Label, list of labels or `{ group, labels }` mapping
+    #[serde(default, rename = "runs-on")]
+    pub runs_on: Option<Value>,
+    #[serde(default)]
+    pub strategy: Option<Strategy>,
+    /// Environment name or `{ name, url }` mapping
+    #[serde(default)]
+    pub environment: Option<Value>,
+    #[serde(default)]
+    pub concurrency: Option<Value>,
+    #[serde(default)]
+    pub steps: Vec<Step>,
+}
+
+impl Job {
+    /// Name of the deployment environment the job targets, if any
+    pub fn environment_name(&self) -> Option<&str> {
+        match self.environment.as_ref()? {
+            Value::String(name) => Some(name),
+            environment => environment.get("name")?.as_str(),
+        }
+    }
+
+    /// Runner labels of the job. A `${{ matrix.<axis> }}` label expands to the
+    /// values of that matrix axis; other expressions are left out.
+    pub fn runner_labels(&self) -> Vec<String> {
+        let labels: Vec<&str> = match &self.runs_on {
+            Some(Value::String(label)) => vec![label],
+            Some(Value::Sequence(labels)) => labels.iter().filter_map(Value::as_str).collect(),
+            Some(runs_on) => match runs_on.get("labels") {
+                Some(Value::String(label)) => vec![label],
+                Some(Value::Sequence(labels)) => labels.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            },
+            None => Vec::new(),
+        };
+
+        let mut resolved = Vec::new();
+        for label in labels {
+            let Some(expr) = label.strip_prefix("${{").and_then(|l| l.strip_suffix("}}")) else {
+                resolved.push(label.to_string());
+                continue;
+            };
+            let axis = expr.trim().strip_prefix("matrix.").and_then(|axis| {
+                self.strategy
+                    .as_ref()?
+                    .matrix
+                    .as_ref()?
+                    .get(axis)?
+                    .as_sequence()
+            });
+            resolved.extend(
+                axis.into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .map(String::from),
+            );
+        }
+        resolved
+    }
+
+    /// Keys of the job's matrix, without `include`/`exclude`. A matrix built
+    /// from an expression (`${{ fromJson(...) }}`) has no static key.
+    pub fn matrix_keys(&self) -> Option<Vec<&str>> {
+        let matrix = self.strategy.as_ref()?.matrix.as_ref()?;
+        Some(match matrix.as_mapping() {
+            Some(matrix) => matrix
+                .keys()
+                .filter_map(Value::as_str)
+                .filter(|key| !matches!(*key, "include" | "exclude"))
+                .collect(),
+            None => Vec::new(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Strategy {
+    #[serde(default)]
+    pub matrix: Option<Value>,
+    /// A boolean or an expression (`${{ github.event_name == 'push' }}`)
+    #[serde(default, rename = "fail-fast")]
+    pub fail_fast: Option<Value>,
+}
+
+impl Strategy {
+    /// Returns true if `fail-fast: false` is set literally; an expression may
+    /// evaluate either way
+    pub fn fail_fast_disabled(&self) -> bool {
+        matches!(self.fail_fast, Some(Value::Bool(false)))
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Step {
+    #[serde(default, deserialize_with = "scalar_string")]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "scalar_string")]
+    pub uses: Option<String>,
+    #[serde(default, deserialize_with = "scalar_string")]
+    pub run: Option<String>,
+}
+
+/// Returns true if a file listed by the contents API is a workflow GitHub
+/// runs: a `.yml`/`.yaml` file (any case) directly under `.github/workflows/`
+pub fn is_workflow_path(path: &str) -> bool {
+    let Some(name) = path.strip_prefix(".github/workflows/") else {
+        return false;
+    };
+    let name = name.to_lowercase();
+    !name.contains('/') && (name.ends_with(".yml") || name.ends_with(".yaml"))
+}
+
+/// Top-level keys every workflow needs that `content` lacks, or the YAML
+/// syntax error
+pub fn missing_keys(content: &str) -> Result<Vec<&'static str>, serde_yaml::Error> {
+    let workflow: Value = serde_yaml::from_str(content)?;
+    Ok(["on", "jobs"]
+        .into_iter()
+        .filter(|key| workflow.get(key).is_none())
+        .collect())
+}
+
+/// Parses a workflow file
+pub fn parse(content: &str) -> Result<Workflow, serde_yaml::Error> {
+    serde_yaml::from_str(content)
+}
+
+/// Parses (file name, content) pairs, leaving out the files that don't parse:
+/// `workflow_valid_yaml` reports those
+pub fn parse_all(workflows: &[(String, String)]) -> Vec<(String, Workflow)> {
+    workflows
+        .iter()
+        .filter_map(|(name, content)| Some((name.clone(), parse(content).ok()?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CI: &str = r#"
+name: CI
+on:
+  push:
+    branches: [main]
+  pull_request:
+permissions:
+  contents: read
+concurrency:
+  group: ci-${{ github.ref }}
+  cancel-in-progress: true
+jobs:
+  test:
+    runs-on: ${{ matrix.os }}
+    strategy:
+      fail-fast: false
+      matrix:
+        os: [ubuntu-latest, windows-latest]
+        rust: [stable, beta]
+        include:
+          - os: macos-latest
+            rust: stable
+    steps:
+      - uses: actions/checkout@v4
+      # - run: cargo deploy
+      - name: Test
+        run: cargo test --all
+  deploy:
+    needs: test
+    runs-on: ubuntu-latest
+    environment:
+      name: production
+      url: https://example.com
+    steps:
+      - run: ./deploy.sh
+"#;
+
+    #[test]
+    fn test_parse_representative_workflow() {
+        let workflow = parse(CI).unwrap();
+        assert_eq!(workflow.on.events(), vec!["push", "pull_request"]);
+        assert!(workflow.has_concurrency());
+        let ids: Vec<&str> = workflow.jobs.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["test", "deploy"]);
+
+        let test = &workflow.jobs[0].1;
+        assert_eq!(test.matrix_keys(), Some(vec!["os", "rust"]));
+        assert!(test.strategy.as_ref().unwrap().fail_fast_disabled());
+        assert_eq!(test.steps.len(), 2);
+        assert_eq!(test.steps[0].uses.as_deref(), Some("actions/checkout@v4"));
+        assert_eq!(test.steps[1].run.as_deref(), Some("cargo test --all"));
+
+        assert_eq!(
+            test.runner_labels(),
+            vec!["ubuntu-latest", "windows-latest"]
+        );
+
+        let deploy = &workflow.jobs[1].1;
+        assert_eq!(deploy.environment_name(), Some("production"));
+        assert_eq!(deploy.matrix_keys(), None);
+    }
+
+    #[test]
+    fn test_parse_trigger_forms() {
+        let events = |content: &str| parse(content).unwrap().on.events().join(",");
+        assert_eq!(events("on: push\njobs: {}\n"), "push");
+        assert_eq!(
+            events("on: [push, pull_request]\njobs: {}\n"),
+            "push,pull_request"
+        );
+        assert_eq!(
+            events("on:\n  workflow_dispatch:\njobs: {}\n"),
+            "workflow_dispatch"
+        );
+        assert_eq!(events("jobs: {}\n"), "");
+    }
+
+    #[test]
+    fn test_runner_labels_forms() {
+        let labels = |runs_on: &str| {
+            let content = format!("on: push\njobs:\n  build:\n    runs-on: {}\n", runs_on);
+            parse(&content).unwrap().jobs[0].1.runner_labels()
+        };
+        assert_eq!(labels("ubuntu-22.04"), vec!["ubuntu-22.04"]);
+        assert_eq!(labels("[self-hosted, linux]"), vec!["self-hosted", "linux"]);
+        assert_eq!(
+            labels("{ group: large, labels: ubuntu-24.04-16core }"),
+            vec!["ubuntu-24.04-16core"]
+        );
+        assert!(labels("${{ inputs.runner }}").is_empty());
+    }
+
+    #[test]
+    fn test_job_level_concurrency() {
+        let workflow = parse("on: push\njobs:\n  deploy:\n    concurrency: production\n").unwrap();
+        assert!(workflow.concurrency.is_none());
+        assert!(workflow.has_concurrency());
+        assert!(!parse("on: push\njobs: {}\n").unwrap().has_concurrency());
+    }
+
+    #[test]
+    fn test_parse_tolerates_expressions_and_numeric_scalars() {
+        let content = r#"
+on: push
+jobs:
+  test:
+    name: 3.11
+    strategy:
+      fail-fast: ${{ github.event_name == 'push' }}
+      matrix:
+        python: ['3.11', '3.12']
+    steps:
+      - name: 3.12
+        run: 42
+"#;
+        let workflow = parse(content).unwrap();
+        let job = &workflow.jobs[0].1;
+        assert_eq!(job.name.as_deref(), Some("3.11"));
+        assert!(!job.strategy.as_ref().unwrap().fail_fast_disabled());
+        assert_eq!(job.steps[0].name.as_deref(), Some("3.12"));
+        assert_eq!(job.steps[0].run.as_deref(), Some("42"));
+        assert_eq!(parse_all(&[("ci.yml".into(), content.into())]).len(), 1);
+    }
+
+    #[test]
+    fn test_is_workflow_path() {
+        assert!(is_workflow_path(".github/workflows/ci.yml"));
+        assert!(is_workflow_path(".github/workflows/Release.YAML"));
+        assert!(!is_workflow_path(".github/workflows/README.md"));
+        assert!(!is_workflow_path(".github/workflows/templates/ci.yml"));
+        assert!(!is_workflow_path(".github/dependabot.yml"));
+        assert!(!is_workflow_path("action.yml"));
+    }
+
+    #[test]
+    fn test_missing_keys() {
+        assert!(missing_keys("on: push\njobs: {}\n").unwrap().is_empty());
+        assert_eq!(
+            missing_keys("name: x\nruns:\n  using: composite\n").unwrap(),
+            vec!["on", "jobs"]
+        );
+        assert!(missing_keys("jobs: [unclosed\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_all_skips_invalid_files() {
+        let workflows = vec![
+            ("ci.yml".to_string(), CI.to_string()),
+            ("broken.yml".to_string(), "jobs: [unclosed\n".to_string()),
+        ];
+        let parsed = parse_all(&workflows);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].0, "ci.yml");
+    }
+
+    #[test]
+    fn test_concurrency_groups_of_workflow_and_jobs() {
+        let parsed = parse(
+            "on: push\nconcurrency: ci-${{ github.ref }}\njobs:\n  deploy:\n    concurrency:\n      group: deploy\n      cancel-in-progress: false\n  test:\n    # concurrency: ignored\n    runs-on: ubuntu-latest\n",
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.concurrency_groups(),
+            vec!["ci-${{ github.ref }}", "deploy"]
+        );
+    }
+}
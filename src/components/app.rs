@@ -1,9 +1,14 @@
+use std::rc::Rc;
+
 use yew::prelude::*;
 
-use crate::checks::CheckEngine;
-use crate::models::ScoreReport;
-use crate::services::GithubClient;
+use crate::checks::{rerun_check, run_analysis, Progress};
+use crate::i18n::{t, Lang};
+use crate::models::{Check, CheckResult, ScoreReport};
+use crate::services::history::{self, HistoryEntry};
+use crate::services::{GithubClient, RateLimit};
 
+use super::comparison::ComparisonView;
 use super::footer::Footer;
 use super::header::Header;
 use super::results::Results;
@@ -15,99 +20,400 @@ pub enum AnalysisState {
     Idle,
     Loading,
     Done(ScoreReport),
+    /// Two repositories analyzed for side-by-side comparison
+    Compared(ScoreReport, ScoreReport),
     Error(String),
 }
 
+/// Changes of the application state, applied by `use_reducer` to the current
+/// state rather than to a copy captured when the change was requested
+pub enum AnalysisAction {
+    Set(AnalysisState),
+    /// Swaps a re-run check result into the displayed report of `repository`
+    /// on `branch`, so overlapping re-runs all land
+    ReplaceResult {
+        repository: String,
+        branch: Option<String>,
+        result: CheckResult,
+    },
+}
+
+impl Reducible for AnalysisState {
+    type Action = AnalysisAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            AnalysisAction::Set(state) => Rc::new(state),
+            AnalysisAction::ReplaceResult {
+                repository,
+                branch,
+                result,
+            } => match &*self {
+                AnalysisState::Done(report)
+                    if report.repository == repository && report.branch == branch =>
+                {
+                    let mut report = report.clone();
+                    if report.replace_result(result) {
+                        Rc::new(AnalysisState::Done(report))
+                    } else {
+                        self
+                    }
+                }
+                // A new analysis started meanwhile: drop the stale result
+                _ => self,
+            },
+        }
+    }
+}
+
 #[component(App)]
 pub fn app() -> Html {
-    let state = use_state(|| AnalysisState::Idle);
+    let state = use_reducer(|| AnalysisState::Idle);
     let token = use_state(|| Option::<String>::None);
+    let lang = use_state(Lang::detect);
+    let recent = use_state(history::list);
+    // (checks done, total, last finished check) while an analysis runs
+    let progress = use_state(|| Option::<(usize, usize, String)>::None);
+    let token_warning = use_state(|| Option::<String>::None);
+    let rate_limit = use_state(|| Option::<RateLimit>::None);
 
     let on_analyze = {
         let state = state.clone();
         let token = token.clone();
-        Callback::from(move |(url, pat): (String, Option<String>)| {
-            let state = state.clone();
-            token.set(pat.clone());
-            let pat = pat.clone();
+        let recent = recent.clone();
+        let progress = progress.clone();
+        let token_warning = token_warning.clone();
+        let rate_limit = rate_limit.clone();
+        Callback::from(
+            move |(url, branch, pat): (String, Option<String>, Option<String>)| {
+                let state = state.clone();
+                let recent = recent.clone();
+                let progress = progress.clone();
+                let token_warning = token_warning.clone();
+                let rate_limit = rate_limit.clone();
+                token.set(pat.clone());
+                let pat = pat.clone();
 
-            state.set(AnalysisState::Loading);
+                state.dispatch(AnalysisAction::Set(AnalysisState::Loading));
+                progress.set(None);
+                token_warning.set(None);
 
-            wasm_bindgen_futures::spawn_local(async move {
-                let client = GithubClient::new(pat);
-                let repo = match GithubClient::parse_repo_url(&url) {
-                    Ok(r) => r,
-                    Err(e) => {
-                        state.set(AnalysisState::Error(e));
-                        return;
+                wasm_bindgen_futures::spawn_local(async move {
+                    match check_token(&pat).await {
+                        Ok(warning) => token_warning.set(warning),
+                        Err(e) => {
+                            state.dispatch(AnalysisAction::Set(AnalysisState::Error(e)));
+                            return;
+                        }
                     }
-                };
 
-                let engine = CheckEngine::new(client);
-                match engine.analyze(&repo).await {
-                    Ok(report) => state.set(AnalysisState::Done(report)),
-                    Err(e) => state.set(AnalysisState::Error(e)),
-                }
-            });
-        })
+                    let on_progress = Callback::from(move |update| progress.set(Some(update)));
+                    let report_progress = |done: usize, total: usize, name: &str| {
+                        on_progress.emit((done, total, name.to_string()));
+                    };
+
+                    let client = GithubClient::new(pat);
+                    let analysis = analyze_url(&url, branch, &client, &report_progress).await;
+                    rate_limit.set(client.rate_limit());
+                    match analysis {
+                        Ok(report) => {
+                            recent.set(history::add(&report));
+                            state.dispatch(AnalysisAction::Set(AnalysisState::Done(report)));
+                        }
+                        Err(e) => state.dispatch(AnalysisAction::Set(AnalysisState::Error(e))),
+                    }
+                });
+            },
+        )
+    };
+
+    let on_compare = {
+        let state = state.clone();
+        let token = token.clone();
+        let recent = recent.clone();
+        let progress = progress.clone();
+        let token_warning = token_warning.clone();
+        let rate_limit = rate_limit.clone();
+        Callback::from(
+            move |(url, other_url, pat): (String, String, Option<String>)| {
+                let state = state.clone();
+                let recent = recent.clone();
+                let progress = progress.clone();
+                let token_warning = token_warning.clone();
+                let rate_limit = rate_limit.clone();
+                token.set(pat.clone());
+
+                state.dispatch(AnalysisAction::Set(AnalysisState::Loading));
+                progress.set(None);
+                token_warning.set(None);
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    match check_token(&pat).await {
+                        Ok(warning) => token_warning.set(warning),
+                        Err(e) => {
+                            state.dispatch(AnalysisAction::Set(AnalysisState::Error(e)));
+                            return;
+                        }
+                    }
+
+                    let on_progress = Callback::from(move |update| progress.set(Some(update)));
+                    let report_progress = |done: usize, total: usize, name: &str| {
+                        on_progress.emit((done, total, name.to_string()));
+                    };
+
+                    let client = GithubClient::new(pat);
+                    let reports = match analyze_url(&url, None, &client, &report_progress).await {
+                        Ok(base) => analyze_url(&other_url, None, &client, &report_progress)
+                            .await
+                            .map(|other| (base, other)),
+                        Err(e) => Err(e),
+                    };
+                    rate_limit.set(client.rate_limit());
+                    match reports {
+                        Ok((base, other)) => {
+                            history::add(&base);
+                            recent.set(history::add(&other));
+                            state.dispatch(AnalysisAction::Set(AnalysisState::Compared(
+                                base, other,
+                            )));
+                        }
+                        Err(e) => state.dispatch(AnalysisAction::Set(AnalysisState::Error(e))),
+                    }
+                });
+            },
+        )
     };
 
     let on_reset = {
         let state = state.clone();
+        let token_warning = token_warning.clone();
         Callback::from(move |_: ()| {
-            state.set(AnalysisState::Idle);
+            token_warning.set(None);
+            state.dispatch(AnalysisAction::Set(AnalysisState::Idle));
+        })
+    };
+
+    let on_rerun = {
+        let state = state.clone();
+        let token = token.clone();
+        Callback::from(move |(check, on_done): (Check, Callback<()>)| {
+            let AnalysisState::Done(report) = &*state else {
+                on_done.emit(());
+                return;
+            };
+            let Ok(mut repo) = GithubClient::parse_repo_url(&report.repository) else {
+                on_done.emit(());
+                return;
+            };
+            repo.branch = report.branch.clone();
+            let repository = report.repository.clone();
+            let primary_language = report.primary_language.clone();
+            let state = state.clone();
+            // Same token as the analysis that produced the report
+            let client = GithubClient::new((*token).clone());
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = rerun_check(client, &repo, &check, primary_language.as_deref()).await;
+                state.dispatch(AnalysisAction::ReplaceResult {
+                    repository,
+                    branch: repo.branch,
+                    result,
+                });
+                on_done.emit(());
+            });
+        })
+    };
+
+    let on_clear_history = {
+        let recent = recent.clone();
+        Callback::from(move |_: MouseEvent| {
+            history::clear();
+            recent.set(Vec::new());
+        })
+    };
+
+    let on_toggle_lang = {
+        let lang = lang.clone();
+        Callback::from(move |_: ()| {
+            let next = lang.toggle();
+            next.persist();
+            lang.set(next);
         })
     };
 
     html! {
-        <div class="app">
-            <Header />
+        <div class="app" lang={lang.code()}>
+            <Header lang={*lang} on_toggle_lang={on_toggle_lang} />
             <main class="main-content">
                 <SearchBar
-                    on_analyze={on_analyze}
+                    on_analyze={on_analyze.clone()}
+                    on_compare={on_compare}
                     is_loading={*state == AnalysisState::Loading}
+                    lang={*lang}
                 />
 
+                if let Some(warning) = &*token_warning {
+                    <div class="token-warning">
+                        <span>{"⚠️"}</span>
+                        <span>{warning}</span>
+                    </div>
+                }
+
                 { match &*state {
                     AnalysisState::Idle => html! {
                         <div class="hero-section">
                             <div class="hero-icon">{"🔍"}</div>
                             <h2 class="hero-title">
-                                {"Analysez la qualité CI/CD de n'importe quel repo GitHub"}
+                                {t("hero.title", *lang)}
                             </h2>
                             <p class="hero-subtitle">
-                                {"Entrez l'URL d'un dépôt GitHub pour obtenir un score détaillé de sa pipeline CI/CD, avec des recommandations d'amélioration."}
+                                {t("hero.subtitle", *lang)}
                             </p>
+                            if !recent.is_empty() {
+                                <div class="recent-section">
+                                    <div class="recent-header">
+                                        <h3 class="recent-title">{t("history.title", *lang)}</h3>
+                                        <button class="recent-clear" onclick={on_clear_history.clone()}>
+                                            {t("history.clear", *lang)}
+                                        </button>
+                                    </div>
+                                    <ul class="recent-list">
+                                        { for recent.iter().map(|entry| html! {
+                                            <RecentItem
+                                                entry={entry.clone()}
+                                                on_analyze={on_analyze.clone()}
+                                                token={(*token).clone()}
+                                            />
+                                        })}
+                                    </ul>
+                                </div>
+                            }
                         </div>
                     },
                     AnalysisState::Loading => html! {
                         <div class="loading-section">
                             <div class="loading-spinner"></div>
-                            <p class="loading-text">{"Analyse en cours..."}</p>
-                            <p class="loading-subtext">
-                                {"Vérification des workflows, tests, sécurité, déploiement..."}
-                            </p>
+                            <p class="loading-text">{t("loading.title", *lang)}</p>
+                            if let Some((done, total, name)) = &*progress {
+                                <div class="loading-progress-bg">
+                                    <div
+                                        class="loading-progress-fill"
+                                        style={format!("width: {}%", done * 100 / (*total).max(1))}
+                                    />
+                                </div>
+                                <p class="loading-subtext">
+                                    {format!("{} : {} ({}/{})", t("loading.progress", *lang), name, done, total)}
+                                </p>
+                            } else {
+                                <p class="loading-subtext">
+                                    {t("loading.subtitle", *lang)}
+                                </p>
+                            }
                         </div>
                     },
                     AnalysisState::Done(report) => html! {
-                        <Results report={report.clone()} on_reset={on_reset.clone()} />
+                        <Results
+                            report={report.clone()}
+                            on_reset={on_reset.clone()}
+                            on_rerun={on_rerun.clone()}
+                            lang={*lang}
+                        />
+                    },
+                    AnalysisState::Compared(base, other) => html! {
+                        <ComparisonView
+                            base={base.clone()}
+                            other={other.clone()}
+                            on_reset={on_reset.clone()}
+                            lang={*lang}
+                        />
                     },
                     AnalysisState::Error(msg) => html! {
                         <div class="error-section">
                             <div class="error-icon">{"⚠️"}</div>
-                            <h3 class="error-title">{"Erreur d'analyse"}</h3>
+                            <h3 class="error-title">{t("error.title", *lang)}</h3>
                             <p class="error-message">{msg}</p>
                             <button class="btn-secondary" onclick={
                                 let on_reset = on_reset.clone();
                                 move |_| on_reset.emit(())
                             }>
-                                {"Réessayer"}
+                                {t("error.retry", *lang)}
                             </button>
                         </div>
                     },
                 }}
             </main>
-            <Footer />
+            <Footer rate_limit={*rate_limit} />
         </div>
     }
 }
+
+/// Validates the token, if any, before an analysis. An invalid token is an
+/// error; a classic token without the `repo` scope yields a warning since the
+/// checks needing it would silently be skipped.
+async fn check_token(token: &Option<String>) -> Result<Option<String>, String> {
+    let Some(token) = token else {
+        return Ok(None);
+    };
+    match GithubClient::new(Some(token.clone())).validate_token().await {
+        Ok(scopes) if !scopes.is_empty() && !scopes.iter().any(|s| s == "repo") => Ok(Some(
+            "Le token n'a pas le scope « repo » : les checks avancés (protection de branche, environnements…) risquent d'être ignorés."
+                .to_string(),
+        )),
+        Ok(_) => Ok(None),
+        Err(e) if e.status == 401 => Err(
+            "Token GitHub invalide ou expiré. Vérifiez-le ou lancez l'analyse sans token."
+                .to_string(),
+        ),
+        // Network hiccups or rate limiting: let the analysis report them
+        Err(_) => Ok(None),
+    }
+}
+
+/// Parses a repo URL and runs the full analysis on it, on `branch` or on the
+/// default branch
+async fn analyze_url(
+    url: &str,
+    branch: Option<String>,
+    client: &GithubClient,
+    on_progress: Progress<'_>,
+) -> Result<ScoreReport, String> {
+    let mut repo = GithubClient::parse_repo_url(url)?;
+    repo.branch = branch;
+    run_analysis(client.clone(), &repo, Some(on_progress)).await
+}
+
+// ── Recent analysis ──
+
+#[derive(Properties, PartialEq, Clone)]
+struct RecentItemProps {
+    entry: HistoryEntry,
+    on_analyze: Callback<(String, Option<String>, Option<String>)>,
+    token: Option<String>,
+}
+
+#[component(RecentItem)]
+fn recent_item(props: &RecentItemProps) -> Html {
+    let entry = &props.entry;
+    let onclick = {
+        let on_analyze = props.on_analyze.clone();
+        let repo = entry.repo.clone();
+        let branch = entry.branch.clone();
+        let token = props.token.clone();
+        Callback::from(move |_: MouseEvent| {
+            on_analyze.emit((repo.clone(), branch.clone(), token.clone()))
+        })
+    };
+    let label = match entry.branch {
+        Some(ref branch) => format!("{} · {}", entry.repo, branch),
+        None => entry.repo.clone(),
+    };
+
+    html! {
+        <li>
+            <button class="recent-item" {onclick}>
+                <span class="recent-repo">{label}</span>
+                <span class="recent-score">{format!("{:.0}%", entry.percentage)}</span>
+            </button>
+        </li>
+    }
+}
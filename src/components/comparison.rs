@@ -0,0 +1,108 @@
+use yew::prelude::*;
+
+use crate::i18n::{t, Lang};
+use crate::models::{CheckStatus, DeltaKind, ScoreReport};
+
+use super::score_gauge::ScoreGauge;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ComparisonViewProps {
+    pub base: ScoreReport,
+    pub other: ScoreReport,
+    pub on_reset: Callback<()>,
+    pub lang: Lang,
+}
+
+#[component(ComparisonView)]
+pub fn comparison_view(props: &ComparisonViewProps) -> Html {
+    let lang = props.lang;
+    let deltas = props.base.diff(&props.other);
+    let count = |kind: DeltaKind| deltas.iter().filter(|d| d.kind() == kind).count();
+
+    html! {
+        <div class="results-section comparison-section">
+            <div class="results-header">
+                <div class="results-repo">
+                    <span class="results-repo-icon">{"⇄"}</span>
+                    <span class="results-repo-link">
+                        {format!("{} vs {}", props.base.repository, props.other.repository)}
+                    </span>
+                </div>
+                <button class="btn-secondary" onclick={
+                    let on_reset = props.on_reset.clone();
+                    move |_| on_reset.emit(())
+                }>
+                    {t("comparison.new_analysis", lang)}
+                </button>
+            </div>
+
+            // ── Side-by-side scores ──
+            <div class="comparison-scores">
+                { for [&props.base, &props.other].into_iter().map(|report| html! {
+                    <div class="comparison-score">
+                        <ScoreGauge
+                            passed={report.passed}
+                            total={report.total}
+                            percentage={report.percentage()}
+                        />
+                        <p class="comparison-repo">{&report.repository}</p>
+                    </div>
+                })}
+            </div>
+
+            <p class="comparison-summary">
+                {format!(
+                    "{} {} • {} {} • {} {} • {} {}",
+                    count(DeltaKind::Improved),
+                    t("comparison.summary_improved", lang),
+                    count(DeltaKind::Worse),
+                    t("comparison.summary_worse", lang),
+                    count(DeltaKind::Same),
+                    t("comparison.summary_same", lang),
+                    count(DeltaKind::NotComparable),
+                    t("comparison.summary_not_comparable", lang),
+                )}
+            </p>
+
+            // ── Per-check deltas ──
+            <div class="category-card">
+                <div class="comparison-row comparison-row-head">
+                    <span>{t("comparison.check", lang)}</span>
+                    <span>{&props.base.repository}</span>
+                    <span>{&props.other.repository}</span>
+                    <span>{t("comparison.delta", lang)}</span>
+                </div>
+                { for deltas.iter().map(|delta| {
+                    let (delta_icon, delta_key, delta_class) = match delta.kind() {
+                        DeltaKind::Improved => ("▲", "comparison.delta_improved", "check-passed"),
+                        DeltaKind::Worse => ("▼", "comparison.delta_worse", "check-failed"),
+                        DeltaKind::Same => ("=", "comparison.delta_same", "check-skipped"),
+                        DeltaKind::NotComparable => {
+                            ("—", "comparison.delta_not_comparable", "check-skipped")
+                        }
+                    };
+                    html! {
+                        <div class={classes!("comparison-row", delta_class)}>
+                            <span class="check-name">{&delta.check.name}</span>
+                            <span>{status_icon(delta.base.as_ref())}</span>
+                            <span>{status_icon(delta.other.as_ref())}</span>
+                            <span class="comparison-delta">
+                                {format!("{} {}", delta_icon, t(delta_key, lang))}
+                            </span>
+                        </div>
+                    }
+                })}
+            </div>
+        </div>
+    }
+}
+
+fn status_icon(status: Option<&CheckStatus>) -> &'static str {
+    match status {
+        Some(CheckStatus::Passed) => "✓",
+        Some(CheckStatus::Failed) => "✗",
+        Some(CheckStatus::Warning) => "!",
+        Some(CheckStatus::Skipped) => "—",
+        None => "·",
+    }
+}
@@ -1,9 +1,22 @@
 use yew::prelude::*;
 
+use crate::services::RateLimit;
+
+#[derive(Properties, PartialEq)]
+pub struct FooterProps {
+    /// API quota left after the last analysis
+    pub rate_limit: Option<RateLimit>,
+}
+
 #[component(Footer)]
-pub fn footer() -> Html {
+pub fn footer(props: &FooterProps) -> Html {
     html! {
         <footer class="footer">
+            if let Some(rate_limit) = props.rate_limit {
+                <p class="footer-rate-limit">
+                    {format!("API : {}/{} restants", rate_limit.remaining, rate_limit.limit)}
+                </p>
+            }
             <p>
                 {"Propulsé par Rust + WebAssembly • "}
                 <a href="https://github.com/FabLrc/GithubCICDChecker" target="_blank" rel="noopener noreferrer">
@@ -1,15 +1,32 @@
 use yew::prelude::*;
 
+use crate::i18n::{t, Lang};
+
+#[derive(Properties, PartialEq)]
+pub struct HeaderProps {
+    pub lang: Lang,
+    pub on_toggle_lang: Callback<()>,
+}
+
 #[component(Header)]
-pub fn header() -> Html {
+pub fn header(props: &HeaderProps) -> Html {
+    let lang = props.lang;
+    let on_toggle_lang = {
+        let on_toggle_lang = props.on_toggle_lang.clone();
+        Callback::from(move |_: MouseEvent| on_toggle_lang.emit(()))
+    };
+
     html! {
         <header class="header">
             <div class="header-inner">
                 <div class="header-logo">
                     <span class="header-icon">{"⚡"}</span>
-                    <h1 class="header-title">{"GitHub CI/CD Checker"}</h1>
+                    <h1 class="header-title">{t("header.title", lang)}</h1>
                 </div>
                 <nav class="header-nav">
+                    <button type="button" class="header-link lang-toggle" onclick={on_toggle_lang}>
+                        {t("header.lang_toggle", lang)}
+                    </button>
                     <a href="https://github.com" target="_blank" rel="noopener" class="header-link">
                         {"GitHub"}
                     </a>
@@ -1,4 +1,5 @@
 mod app;
+mod comparison;
 mod footer;
 mod header;
 mod results;
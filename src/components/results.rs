@@ -1,6 +1,10 @@
+use std::collections::HashSet;
+
 use yew::prelude::*;
 
-use crate::models::{CategoryScore, CheckResult, CheckStatus, ScoreReport};
+use crate::i18n::{t, Lang};
+use crate::models::{CategoryScore, Check, CheckCategory, CheckResult, CheckStatus, ScoreReport};
+use crate::services::export;
 
 use super::score_gauge::ScoreGauge;
 
@@ -8,11 +12,72 @@ use super::score_gauge::ScoreGauge;
 pub struct ResultsProps {
     pub report: ScoreReport,
     pub on_reset: Callback<()>,
+    /// Re-runs a check; the second callback fires once its result is in
+    pub on_rerun: Callback<(Check, Callback<()>)>,
+    pub lang: Lang,
 }
 
 #[component(Results)]
 pub fn results(props: &ResultsProps) -> Html {
     let report = &props.report;
+    let lang = props.lang;
+
+    let on_export_json = {
+        let report = report.clone();
+        Callback::from(move |_: MouseEvent| {
+            let filename = export::report_filename(&report, "json");
+            let downloaded = export::report_to_json(&report)
+                .map_err(|e| e.to_string())
+                .and_then(|json| {
+                    export::download_file(&filename, &json, "application/json")
+                        .map_err(|e| format!("{:?}", e))
+                });
+            if let Err(e) = downloaded {
+                log::error!("Export JSON impossible : {}", e);
+            }
+        })
+    };
+
+    let on_copy_markdown = {
+        let report = report.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Err(e) = export::copy_to_clipboard(&report.to_markdown()) {
+                log::error!("Copie Markdown impossible : {:?}", e);
+            }
+        })
+    };
+
+    let on_copy_badge = {
+        let report = report.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Err(e) = export::copy_to_clipboard(&report.to_badge_svg()) {
+                log::error!("Copie du badge impossible : {:?}", e);
+            }
+        })
+    };
+
+    // Categories are expanded unless listed here
+    let collapsed = use_state(HashSet::<CheckCategory>::new);
+    let on_expansion = {
+        let collapsed = collapsed.clone();
+        Callback::from(move |action: ExpansionAction| {
+            collapsed.set(reduce_collapsed(&collapsed, action));
+        })
+    };
+    let all_categories: Vec<CheckCategory> = report
+        .categories
+        .iter()
+        .map(|c| c.category.clone())
+        .collect();
+
+    let status_filter = use_state(|| Option::<CheckStatus>::None);
+    let filter_chips = [
+        (None, "results.filter_all"),
+        (Some(CheckStatus::Failed), "results.filter_failed"),
+        (Some(CheckStatus::Warning), "results.filter_warning"),
+        (Some(CheckStatus::Passed), "results.filter_passed"),
+        (Some(CheckStatus::Skipped), "results.filter_skipped"),
+    ];
 
     html! {
         <div class="results-section">
@@ -28,24 +93,92 @@ pub fn results(props: &ResultsProps) -> Html {
                     >
                         {&report.repository}
                     </a>
+                    if let Some(ref branch) = report.branch {
+                        <span class="results-language">{format!("⎇ {}", branch)}</span>
+                    }
+                    if let Some(ref language) = report.primary_language {
+                        <span class="results-language">{language}</span>
+                    }
+                </div>
+                <div class="results-actions">
+                    <button class="btn-secondary" onclick={on_export_json}>
+                        {"⬇ Export JSON"}
+                    </button>
+                    <button class="btn-secondary" onclick={on_copy_markdown}>
+                        {"📋 Copier en Markdown"}
+                    </button>
+                    <button class="btn-secondary" onclick={on_copy_badge}>
+                        {"🏷 Copier le badge SVG"}
+                    </button>
+                    <button class="btn-secondary" onclick={
+                        let on_reset = props.on_reset.clone();
+                        move |_| on_reset.emit(())
+                    }>
+                        {"← Nouvelle analyse"}
+                    </button>
                 </div>
-                <button class="btn-secondary" onclick={
-                    let on_reset = props.on_reset.clone();
-                    move |_| on_reset.emit(())
-                }>
-                    {"← Nouvelle analyse"}
-                </button>
             </div>
 
             // ── Score gauge (PageSpeed style) ──
             <div class="results-score-area">
-                <ScoreGauge passed={report.passed} total={report.total} />
+                <ScoreGauge
+                    passed={report.passed}
+                    total={report.total}
+                    percentage={report.percentage()}
+                />
+            </div>
+
+            // ── Status filter ──
+            <div class="status-filters">
+                <button class="filter-chip" onclick={
+                    let on_expansion = on_expansion.clone();
+                    move |_| on_expansion.emit(ExpansionAction::ExpandAll)
+                }>
+                    {t("results.expand_all", lang)}
+                </button>
+                <button class="filter-chip" onclick={
+                    let on_expansion = on_expansion.clone();
+                    let all_categories = all_categories.clone();
+                    move |_| on_expansion.emit(ExpansionAction::CollapseAll(all_categories.clone()))
+                }>
+                    {t("results.collapse_all", lang)}
+                </button>
+                <span class="status-filters-separator"></span>
+                { for filter_chips.into_iter().map(|(filter, key)| {
+                    let active = *status_filter == filter;
+                    let onclick = {
+                        let status_filter = status_filter.clone();
+                        let filter = filter.clone();
+                        Callback::from(move |_: MouseEvent| status_filter.set(filter.clone()))
+                    };
+                    html! {
+                        <button
+                            class={classes!("filter-chip", active.then_some("filter-chip-active"))}
+                            {onclick}
+                        >
+                            {t(key, lang)}
+                        </button>
+                    }
+                })}
             </div>
 
             // ── Category breakdown ──
             <div class="categories-grid">
                 { for report.categories.iter().map(|cat| html! {
-                    <CategoryCard category={cat.clone()} />
+                    <CategoryCard
+                        category={cat.clone()}
+                        expanded={!collapsed.contains(&cat.category)}
+                        on_toggle={
+                            let on_expansion = on_expansion.clone();
+                            let category = cat.category.clone();
+                            Callback::from(move |_: ()| {
+                                on_expansion.emit(ExpansionAction::Toggle(category.clone()))
+                            })
+                        }
+                        filter={(*status_filter).clone()}
+                        on_rerun={props.on_rerun.clone()}
+                        {lang}
+                    />
                 })}
             </div>
 
@@ -57,23 +190,52 @@ pub fn results(props: &ResultsProps) -> Html {
     }
 }
 
+/// Expansion change of the category cards
+#[derive(Debug, Clone, PartialEq)]
+enum ExpansionAction {
+    Toggle(CheckCategory),
+    ExpandAll,
+    CollapseAll(Vec<CheckCategory>),
+}
+
+/// Applies an expansion change to the set of collapsed categories
+fn reduce_collapsed(
+    collapsed: &HashSet<CheckCategory>,
+    action: ExpansionAction,
+) -> HashSet<CheckCategory> {
+    match action {
+        ExpansionAction::Toggle(category) => {
+            let mut next = collapsed.clone();
+            if !next.remove(&category) {
+                next.insert(category);
+            }
+            next
+        }
+        ExpansionAction::ExpandAll => HashSet::new(),
+        ExpansionAction::CollapseAll(categories) => categories.into_iter().collect(),
+    }
+}
+
 // ── Category Card ──
 
 #[derive(Properties, PartialEq, Clone)]
 struct CategoryCardProps {
     category: CategoryScore,
+    filter: Option<CheckStatus>,
+    expanded: bool,
+    on_toggle: Callback<()>,
+    on_rerun: Callback<(Check, Callback<()>)>,
+    lang: Lang,
 }
 
 #[component(CategoryCard)]
 fn category_card(props: &CategoryCardProps) -> Html {
     let cat = &props.category;
-    let expanded = use_state(|| true);
+    let expanded = props.expanded;
 
     let toggle = {
-        let expanded = expanded.clone();
-        Callback::from(move |_: MouseEvent| {
-            expanded.set(!*expanded);
-        })
+        let on_toggle = props.on_toggle.clone();
+        Callback::from(move |_: MouseEvent| on_toggle.emit(()))
     };
 
     let pct = cat.percentage();
@@ -105,16 +267,23 @@ fn category_card(props: &CategoryCardProps) -> Html {
                         />
                     </div>
                     <span class="category-chevron">
-                        if *expanded { {"▾"} } else { {"▸"} }
+                        if expanded { {"▾"} } else { {"▸"} }
                     </span>
                 </div>
             </div>
 
-            if *expanded {
+            if expanded {
                 <div class="category-checks">
-                    { for cat.results.iter().map(|r| html! {
-                        <CheckRow result={r.clone()} />
-                    })}
+                    { for cat.results.iter()
+                        .filter(|r| r.matches_status(props.filter.as_ref()))
+                        .map(|r| html! {
+                            <CheckRow
+                                result={r.clone()}
+                                on_rerun={props.on_rerun.clone()}
+                                lang={props.lang}
+                            />
+                        })
+                    }
                 </div>
             }
         </div>
@@ -126,12 +295,15 @@ fn category_card(props: &CategoryCardProps) -> Html {
 #[derive(Properties, PartialEq, Clone)]
 struct CheckRowProps {
     result: CheckResult,
+    on_rerun: Callback<(Check, Callback<()>)>,
+    lang: Lang,
 }
 
 #[component(CheckRow)]
 fn check_row(props: &CheckRowProps) -> Html {
     let r = &props.result;
     let show_detail = use_state(|| false);
+    let rerunning = use_state(|| false);
 
     let toggle = {
         let show_detail = show_detail.clone();
@@ -140,6 +312,21 @@ fn check_row(props: &CheckRowProps) -> Html {
         })
     };
 
+    let on_rerun = {
+        let on_rerun = props.on_rerun.clone();
+        let check = r.check.clone();
+        let rerunning = rerunning.clone();
+        Callback::from(move |e: MouseEvent| {
+            // Keep the detail panel open
+            e.stop_propagation();
+            rerunning.set(true);
+            let rerunning = rerunning.clone();
+            let on_done = Callback::from(move |_: ()| rerunning.set(false));
+            on_rerun.emit((check.clone(), on_done));
+        })
+    };
+    let can_rerun = matches!(r.status, CheckStatus::Failed | CheckStatus::Warning);
+
     let (status_icon, status_class) = match r.status {
         CheckStatus::Passed => ("✓", "check-passed"),
         CheckStatus::Failed => ("✗", "check-failed"),
@@ -165,8 +352,47 @@ fn check_row(props: &CheckRowProps) -> Html {
                             <span>{suggestion}</span>
                         </div>
                     }
+                    if can_rerun {
+                        <button
+                            class="btn-secondary check-rerun"
+                            onclick={on_rerun}
+                            disabled={*rerunning}
+                        >
+                            if *rerunning {
+                                {t("results.rerunning", props.lang)}
+                            } else {
+                                {t("results.rerun", props.lang)}
+                            }
+                        </button>
+                    }
                 </div>
             }
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduce_collapsed() {
+        let none = HashSet::new();
+
+        let one = reduce_collapsed(&none, ExpansionAction::Toggle(CheckCategory::Securite));
+        assert!(one.contains(&CheckCategory::Securite));
+        assert_eq!(
+            reduce_collapsed(&one, ExpansionAction::Toggle(CheckCategory::Securite)),
+            none
+        );
+
+        let all = reduce_collapsed(
+            &one,
+            ExpansionAction::CollapseAll(vec![CheckCategory::Pipeline, CheckCategory::Securite]),
+        );
+        assert_eq!(all.len(), 2);
+        assert!(all.contains(&CheckCategory::Pipeline));
+
+        assert!(reduce_collapsed(&all, ExpansionAction::ExpandAll).is_empty());
+    }
+}
@@ -1,44 +1,55 @@
 use yew::prelude::*;
 
+use crate::models::letter_grade;
+
 #[derive(Properties, PartialEq)]
 pub struct ScoreGaugeProps {
     pub passed: u32,
     pub total: u32,
+    /// Unrounded score of the report: grading the displayed, rounded value
+    /// would show 89.6 % as an A
+    pub percentage: f64,
 }
 
 #[component(ScoreGauge)]
 pub fn score_gauge(props: &ScoreGaugeProps) -> Html {
-    let percentage = if props.total > 0 {
-        ((props.passed as f64 / props.total as f64) * 100.0).round() as u32
-    } else {
-        0
-    };
+    let percentage = gauge_percentage(props.passed, props.total);
+    let score = props.percentage;
 
-    let color = if percentage >= 90 {
+    let color = if score >= 90.0 {
         "#0cce6b"
-    } else if percentage >= 50 {
+    } else if score >= 50.0 {
         "#ffa400"
     } else {
         "#ff4e42"
     };
 
-    let label = if percentage >= 90 {
+    let label = if score >= 90.0 {
         "Excellent"
-    } else if percentage >= 70 {
+    } else if score >= 70.0 {
         "Bon"
-    } else if percentage >= 50 {
+    } else if score >= 50.0 {
         "À améliorer"
     } else {
         "Insuffisant"
     };
 
+    let grade = letter_grade(score);
+
     // SVG circular gauge (like PageSpeed Insights)
     let circumference = 2.0 * std::f64::consts::PI * 54.0;
-    let dash_offset = circumference * (1.0 - percentage as f64 / 100.0);
+    let dash_offset = circumference * (1.0 - score / 100.0);
 
     html! {
         <div class="score-gauge">
-            <svg class="gauge-svg" viewBox="0 0 120 120" width="200" height="200">
+            <svg
+                class="gauge-svg"
+                viewBox="0 0 120 120"
+                width="200"
+                height="200"
+                role="img"
+                aria-label={gauge_aria_label(props.passed, props.total)}
+            >
                 // Background circle
                 <circle
                     cx="60" cy="60" r="54"
@@ -56,7 +67,8 @@ pub fn score_gauge(props: &ScoreGaugeProps) -> Html {
                     stroke-dasharray={format!("{}", circumference)}
                     stroke-dashoffset={format!("{}", dash_offset)}
                     transform="rotate(-90 60 60)"
-                    class="gauge-arc"
+                    class="gauge-arc gauge-arc-reveal"
+                    style={format!("--gauge-circumference: {}", circumference)}
                 />
                 // Score text
                 <text
@@ -77,8 +89,47 @@ pub fn score_gauge(props: &ScoreGaugeProps) -> Html {
                 </text>
             </svg>
             <div class="gauge-badge" style={format!("color: {}", color)}>
+                <span class="gauge-letter" style={format!("border-color: {}", color)}>
+                    {grade}
+                </span>
                 {label}
             </div>
         </div>
     }
 }
+
+/// Rounded score percentage shown by the gauge
+fn gauge_percentage(passed: u32, total: u32) -> u32 {
+    if total > 0 {
+        ((passed as f64 / total as f64) * 100.0).round() as u32
+    } else {
+        0
+    }
+}
+
+/// Text alternative of the gauge for screen readers
+fn gauge_aria_label(passed: u32, total: u32) -> String {
+    format!(
+        "Score {} sur 100 ({}/{} checks réussis)",
+        gauge_percentage(passed, total),
+        passed,
+        total
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aria_label_from_passed_and_total() {
+        assert_eq!(
+            gauge_aria_label(18, 25),
+            "Score 72 sur 100 (18/25 checks réussis)"
+        );
+        assert_eq!(
+            gauge_aria_label(0, 0),
+            "Score 0 sur 100 (0/0 checks réussis)"
+        );
+    }
+}
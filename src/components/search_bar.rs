@@ -1,22 +1,35 @@
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
+use crate::i18n::{t, Lang};
+
 #[derive(Properties, PartialEq)]
 pub struct SearchBarProps {
-    pub on_analyze: Callback<(String, Option<String>)>,
+    /// (repo URL, branch, token); no branch means the default one
+    pub on_analyze: Callback<(String, Option<String>, Option<String>)>,
+    /// (repo URL, reference repo URL, token) in comparison mode
+    pub on_compare: Callback<(String, String, Option<String>)>,
     pub is_loading: bool,
+    pub lang: Lang,
 }
 
 #[component(SearchBar)]
 pub fn search_bar(props: &SearchBarProps) -> Html {
     let url_ref = use_node_ref();
     let token_ref = use_node_ref();
+    let compare_ref = use_node_ref();
+    let branch_ref = use_node_ref();
     let show_token = use_state(|| false);
+    let compare_mode = use_state(|| false);
+    let lang = props.lang;
 
     let on_submit = {
         let url_ref = url_ref.clone();
         let token_ref = token_ref.clone();
+        let compare_ref = compare_ref.clone();
+        let branch_ref = branch_ref.clone();
         let on_analyze = props.on_analyze.clone();
+        let on_compare = props.on_compare.clone();
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
             let url = url_ref
@@ -28,9 +41,27 @@ pub fn search_bar(props: &SearchBarProps) -> Html {
                 .map(|el| el.value())
                 .unwrap_or_default();
 
+            let other_url = compare_ref
+                .cast::<HtmlInputElement>()
+                .map(|el| el.value())
+                .unwrap_or_default();
+            let branch = branch_ref
+                .cast::<HtmlInputElement>()
+                .map(|el| el.value().trim().to_string())
+                .unwrap_or_default();
+
             if !url.is_empty() {
                 let token = if token.is_empty() { None } else { Some(token) };
-                on_analyze.emit((url, token));
+                if other_url.is_empty() {
+                    let branch = if branch.is_empty() {
+                        None
+                    } else {
+                        Some(branch)
+                    };
+                    on_analyze.emit((url, branch, token));
+                } else {
+                    on_compare.emit((url, other_url, token));
+                }
             }
         })
     };
@@ -42,6 +73,13 @@ pub fn search_bar(props: &SearchBarProps) -> Html {
         })
     };
 
+    let toggle_compare = {
+        let compare_mode = compare_mode.clone();
+        Callback::from(move |_: MouseEvent| {
+            compare_mode.set(!*compare_mode);
+        })
+    };
+
     html! {
         <div class="search-section">
             <form class="search-form" onsubmit={on_submit}>
@@ -51,7 +89,7 @@ pub fn search_bar(props: &SearchBarProps) -> Html {
                         ref={url_ref}
                         type="text"
                         class="search-input"
-                        placeholder="Entrez l'URL d'un repo GitHub (ex: rust-lang/rust)"
+                        placeholder={t("search.placeholder", lang)}
                         disabled={props.is_loading}
                         autofocus=true
                     />
@@ -62,19 +100,52 @@ pub fn search_bar(props: &SearchBarProps) -> Html {
                     >
                         if props.is_loading {
                             <span class="btn-spinner"></span>
-                            {"Analyse..."}
+                            {t("search.analyzing", lang)}
+                        } else if *compare_mode {
+                            {t("search.compare", lang)}
                         } else {
-                            {"Analyser"}
+                            {t("search.analyze", lang)}
                         }
                     </button>
                 </div>
 
+                if *compare_mode {
+                    <div class="search-input-wrapper compare-input-wrapper">
+                        <span class="search-icon">{"⇄"}</span>
+                        <input
+                            ref={compare_ref}
+                            type="text"
+                            class="search-input"
+                            placeholder={t("search.compare_placeholder", lang)}
+                            disabled={props.is_loading}
+                        />
+                    </div>
+                } else {
+                    <div class="search-input-wrapper branch-input-wrapper">
+                        <span class="search-icon">{"⎇"}</span>
+                        <input
+                            ref={branch_ref}
+                            type="text"
+                            class="search-input"
+                            placeholder={t("search.branch_placeholder", lang)}
+                            disabled={props.is_loading}
+                        />
+                    </div>
+                }
+
                 <div class="token-section">
+                    <button type="button" class="token-toggle" onclick={toggle_compare}>
+                        if *compare_mode {
+                            {t("search.compare_hide", lang)}
+                        } else {
+                            {t("search.compare_show", lang)}
+                        }
+                    </button>
                     <button type="button" class="token-toggle" onclick={toggle_token}>
                         if *show_token {
-                            {"▾ Masquer le token GitHub"}
+                            {t("search.token_hide", lang)}
                         } else {
-                            {"▸ Token GitHub (optionnel — débloque les checks avancés)"}
+                            {t("search.token_show", lang)}
                         }
                     </button>
                     if *show_token {
@@ -87,7 +158,7 @@ pub fn search_bar(props: &SearchBarProps) -> Html {
                                 disabled={props.is_loading}
                             />
                             <p class="token-hint">
-                                {"Le token n'est jamais stocké. Il est utilisé uniquement pour les appels API dans votre navigateur."}
+                                {t("search.token_hint", lang)}
                             </p>
                         </div>
                     }
@@ -0,0 +1,190 @@
+/// Interface language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    Fr,
+    En,
+}
+
+const STORAGE_KEY: &str = "lang";
+
+impl Lang {
+    /// Maps a BCP 47 tag (e.g. `en-US`) to a supported language, French otherwise
+    pub fn from_code(code: &str) -> Self {
+        if code.trim().to_lowercase().starts_with("en") {
+            Lang::En
+        } else {
+            Lang::Fr
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lang::Fr => "fr",
+            Lang::En => "en",
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            Lang::Fr => Lang::En,
+            Lang::En => Lang::Fr,
+        }
+    }
+
+    /// Stored choice first, then `navigator.language`, then French
+    pub fn detect() -> Self {
+        let Some(window) = web_sys::window() else {
+            return Lang::default();
+        };
+        if let Some(stored) = window
+            .local_storage()
+            .ok()
+            .flatten()
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+        {
+            return Lang::from_code(&stored);
+        }
+        window
+            .navigator()
+            .language()
+            .map(|code| Lang::from_code(&code))
+            .unwrap_or_default()
+    }
+
+    /// Remembers the choice in `localStorage`
+    pub fn persist(&self) {
+        if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+            let _ = storage.set_item(STORAGE_KEY, self.code());
+        }
+    }
+}
+
+/// UI strings: (key, français, English)
+const TRANSLATIONS: &[(&str, &str, &str)] = &[
+    ("header.title", "GitHub CI/CD Checker", "GitHub CI/CD Checker"),
+    ("header.lang_toggle", "English", "Français"),
+    (
+        "search.placeholder",
+        "Entrez l'URL d'un repo GitHub (ex: rust-lang/rust)",
+        "Enter a GitHub repo URL (e.g. rust-lang/rust)",
+    ),
+    ("search.analyze", "Analyser", "Analyze"),
+    (
+        "search.branch_placeholder",
+        "Branche (optionnel — branche par défaut sinon)",
+        "Branch (optional — default branch otherwise)",
+    ),
+    ("search.analyzing", "Analyse...", "Analyzing..."),
+    ("search.compare", "Comparer", "Compare"),
+    (
+        "search.compare_placeholder",
+        "URL du repo de référence (ex: rust-lang/cargo)",
+        "Reference repo URL (e.g. rust-lang/cargo)",
+    ),
+    (
+        "search.compare_show",
+        "▸ Comparer avec un autre repo",
+        "▸ Compare with another repo",
+    ),
+    ("search.compare_hide", "▾ Annuler la comparaison", "▾ Cancel comparison"),
+    ("search.token_hide", "▾ Masquer le token GitHub", "▾ Hide GitHub token"),
+    (
+        "search.token_show",
+        "▸ Token GitHub (optionnel — débloque les checks avancés)",
+        "▸ GitHub token (optional — unlocks advanced checks)",
+    ),
+    (
+        "search.token_hint",
+        "Le token n'est jamais stocké. Il est utilisé uniquement pour les appels API dans votre navigateur.",
+        "The token is never stored. It is only used for API calls from your browser.",
+    ),
+    (
+        "hero.title",
+        "Analysez la qualité CI/CD de n'importe quel repo GitHub",
+        "Analyze the CI/CD quality of any GitHub repo",
+    ),
+    (
+        "hero.subtitle",
+        "Entrez l'URL d'un dépôt GitHub pour obtenir un score détaillé de sa pipeline CI/CD, avec des recommandations d'amélioration.",
+        "Enter a GitHub repository URL to get a detailed score of its CI/CD pipeline, with improvement recommendations.",
+    ),
+    ("loading.title", "Analyse en cours...", "Analysis in progress..."),
+    (
+        "loading.subtitle",
+        "Vérification des workflows, tests, sécurité, déploiement...",
+        "Checking workflows, tests, security, deployment...",
+    ),
+    ("loading.progress", "Vérification", "Checking"),
+    ("error.title", "Erreur d'analyse", "Analysis error"),
+    ("error.retry", "Réessayer", "Retry"),
+    ("history.title", "Récemment analysés", "Recently analyzed"),
+    ("history.clear", "Effacer", "Clear"),
+    ("comparison.new_analysis", "← Nouvelle analyse", "← New analysis"),
+    ("comparison.summary_improved", "amélioré(s)", "improved"),
+    ("comparison.summary_worse", "en recul", "regressed"),
+    ("comparison.summary_same", "identique(s)", "unchanged"),
+    ("comparison.summary_not_comparable", "non comparable(s)", "not comparable"),
+    ("comparison.check", "Check", "Check"),
+    ("comparison.delta", "Écart", "Difference"),
+    ("comparison.delta_improved", "Meilleur", "Better"),
+    ("comparison.delta_worse", "Moins bon", "Worse"),
+    ("comparison.delta_same", "Identique", "Same"),
+    ("comparison.delta_not_comparable", "Non comparable", "Not comparable"),
+    ("results.expand_all", "Tout déplier", "Expand all"),
+    ("results.collapse_all", "Tout replier", "Collapse all"),
+    ("results.filter_all", "Tous", "All"),
+    ("results.filter_failed", "Échoués", "Failed"),
+    ("results.filter_warning", "Avertissements", "Warnings"),
+    ("results.filter_passed", "Réussis", "Passed"),
+    ("results.filter_skipped", "Ignorés", "Skipped"),
+    ("results.rerun", "↻ Relancer ce check", "↻ Re-run this check"),
+    ("results.rerunning", "↻ Relance en cours...", "↻ Re-running..."),
+];
+
+/// Looks up a UI string, falling back to the key itself when it is unknown
+pub fn t(key: &'static str, lang: Lang) -> &'static str {
+    TRANSLATIONS
+        .iter()
+        .find(|(k, _, _)| *k == key)
+        .map(|(_, fr, en)| match lang {
+            Lang::Fr => *fr,
+            Lang::En => *en,
+        })
+        .unwrap_or(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_t_returns_string_for_each_language() {
+        assert_eq!(t("search.analyze", Lang::Fr), "Analyser");
+        assert_eq!(t("search.analyze", Lang::En), "Analyze");
+        assert_eq!(t("error.title", Lang::Fr), "Erreur d'analyse");
+        assert_eq!(t("error.title", Lang::En), "Analysis error");
+        assert_eq!(t("unknown.key", Lang::En), "unknown.key");
+    }
+
+    #[test]
+    fn test_t_filter_chip_labels() {
+        assert_eq!(t("results.filter_failed", Lang::Fr), "Échoués");
+        assert_eq!(t("results.filter_failed", Lang::En), "Failed");
+        assert_eq!(t("results.filter_skipped", Lang::En), "Skipped");
+    }
+
+    #[test]
+    fn test_t_comparison_labels() {
+        assert_eq!(t("comparison.delta_worse", Lang::Fr), "Moins bon");
+        assert_eq!(t("comparison.delta_worse", Lang::En), "Worse");
+        assert_eq!(t("comparison.summary_worse", Lang::En), "regressed");
+    }
+
+    #[test]
+    fn test_lang_from_code() {
+        assert_eq!(Lang::from_code("en-US"), Lang::En);
+        assert_eq!(Lang::from_code("fr-FR"), Lang::Fr);
+        assert_eq!(Lang::from_code("de"), Lang::Fr);
+    }
+}
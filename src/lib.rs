@@ -1,4 +1,5 @@
 pub mod checks;
 pub mod components;
+pub mod i18n;
 pub mod models;
 pub mod services;
@@ -98,4 +98,37 @@ impl CheckResult {
             suggestion: None,
         }
     }
+
+    /// Whether this result is shown under a status filter (`None` shows everything)
+    pub fn matches_status(&self, filter: Option<&CheckStatus>) -> bool {
+        filter.is_none_or(|status| *status == self.status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(status: CheckStatus) -> CheckResult {
+        CheckResult {
+            check: Check {
+                id: "x".into(),
+                name: "X".into(),
+                description: String::new(),
+                category: CheckCategory::Pipeline,
+            },
+            status,
+            detail: String::new(),
+            suggestion: None,
+        }
+    }
+
+    #[test]
+    fn test_matches_status_filter() {
+        let failed = result(CheckStatus::Failed);
+        assert!(failed.matches_status(None));
+        assert!(failed.matches_status(Some(&CheckStatus::Failed)));
+        assert!(!failed.matches_status(Some(&CheckStatus::Passed)));
+        assert!(!result(CheckStatus::Skipped).matches_status(Some(&CheckStatus::Warning)));
+    }
 }
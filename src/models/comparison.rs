@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+
+use super::check::{Check, CheckStatus};
+use super::score::ScoreReport;
+
+/// How a check fares in the compared report relative to the base report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeltaKind {
+    Improved,
+    Worse,
+    Same,
+    /// Skipped or missing on at least one side
+    NotComparable,
+}
+
+/// Status of one check in two reports (`None` when a report lacks the check)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckDelta {
+    pub check: Check,
+    pub base: Option<CheckStatus>,
+    pub other: Option<CheckStatus>,
+}
+
+impl CheckDelta {
+    pub fn kind(&self) -> DeltaKind {
+        match (
+            self.base.as_ref().and_then(status_rank),
+            self.other.as_ref().and_then(status_rank),
+        ) {
+            (Some(base), Some(other)) if other > base => DeltaKind::Improved,
+            (Some(base), Some(other)) if other < base => DeltaKind::Worse,
+            (Some(_), Some(_)) => DeltaKind::Same,
+            _ if self.base.is_some() && self.base == self.other => DeltaKind::Same,
+            _ => DeltaKind::NotComparable,
+        }
+    }
+}
+
+/// Orders evaluated statuses; skipped checks are not ranked
+fn status_rank(status: &CheckStatus) -> Option<u8> {
+    match status {
+        CheckStatus::Failed => Some(0),
+        CheckStatus::Warning => Some(1),
+        CheckStatus::Passed => Some(2),
+        CheckStatus::Skipped => None,
+    }
+}
+
+impl ScoreReport {
+    /// Per-check comparison of `other` against this report: checks of this
+    /// report first, in order, then those only `other` contains
+    pub fn diff(&self, other: &ScoreReport) -> Vec<CheckDelta> {
+        let base_results = self.categories.iter().flat_map(|cat| &cat.results);
+        let other_results = || other.categories.iter().flat_map(|cat| &cat.results);
+
+        let mut deltas: Vec<CheckDelta> = base_results
+            .map(|r| CheckDelta {
+                check: r.check.clone(),
+                base: Some(r.status.clone()),
+                other: other_results()
+                    .find(|o| o.check.id == r.check.id)
+                    .map(|o| o.status.clone()),
+            })
+            .collect();
+
+        let other_only: Vec<CheckDelta> = other_results()
+            .filter(|o| !deltas.iter().any(|d| d.check.id == o.check.id))
+            .map(|o| CheckDelta {
+                check: o.check.clone(),
+                base: None,
+                other: Some(o.status.clone()),
+            })
+            .collect();
+        deltas.extend(other_only);
+        deltas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CategoryScore, CheckCategory, CheckResult};
+
+    fn report(repository: &str, statuses: &[(&str, CheckStatus)]) -> ScoreReport {
+        let results: Vec<CheckResult> = statuses
+            .iter()
+            .map(|(id, status)| CheckResult {
+                check: Check {
+                    id: id.to_string(),
+                    name: format!("Check {}", id),
+                    description: String::new(),
+                    category: CheckCategory::Pipeline,
+                },
+                status: status.clone(),
+                detail: String::new(),
+                suggestion: None,
+            })
+            .collect();
+        let (passed, total) = CategoryScore::tally(&results);
+        ScoreReport {
+            repository: repository.into(),
+            primary_language: None,
+            branch: None,
+            passed,
+            total,
+            categories: vec![CategoryScore {
+                category: CheckCategory::Pipeline,
+                passed,
+                total,
+                results,
+            }],
+            analyzed_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_diff_classifies_common_checks() {
+        let base = report(
+            "a/base",
+            &[
+                ("a", CheckStatus::Failed),
+                ("b", CheckStatus::Passed),
+                ("c", CheckStatus::Warning),
+                ("d", CheckStatus::Skipped),
+            ],
+        );
+        let other = report(
+            "a/other",
+            &[
+                ("a", CheckStatus::Warning),
+                ("b", CheckStatus::Failed),
+                ("c", CheckStatus::Warning),
+                ("d", CheckStatus::Passed),
+            ],
+        );
+
+        let kinds: Vec<DeltaKind> = base.diff(&other).iter().map(|d| d.kind()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DeltaKind::Improved,
+                DeltaKind::Worse,
+                DeltaKind::Same,
+                DeltaKind::NotComparable
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_keeps_checks_present_in_one_report_only() {
+        let base = report(
+            "a/base",
+            &[("a", CheckStatus::Passed), ("b", CheckStatus::Failed)],
+        );
+        let other = report(
+            "a/other",
+            &[("a", CheckStatus::Passed), ("c", CheckStatus::Passed)],
+        );
+
+        let deltas = base.diff(&other);
+        let ids: Vec<&str> = deltas.iter().map(|d| d.check.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+
+        assert_eq!(deltas[1].base, Some(CheckStatus::Failed));
+        assert_eq!(deltas[1].other, None);
+        assert_eq!(deltas[1].kind(), DeltaKind::NotComparable);
+
+        assert_eq!(deltas[2].base, None);
+        assert_eq!(deltas[2].other, Some(CheckStatus::Passed));
+        assert_eq!(deltas[2].kind(), DeltaKind::NotComparable);
+    }
+
+    #[test]
+    fn test_diff_of_skipped_on_both_sides_is_same() {
+        let base = report("a/base", &[("a", CheckStatus::Skipped)]);
+        let other = report("a/other", &[("a", CheckStatus::Skipped)]);
+        assert_eq!(base.diff(&other)[0].kind(), DeltaKind::Same);
+    }
+}
@@ -1,5 +1,7 @@
 mod check;
+mod comparison;
 mod score;
 
 pub use check::{Check, CheckCategory, CheckResult, CheckStatus};
-pub use score::{CategoryScore, ScoreReport};
+pub use comparison::{CheckDelta, DeltaKind};
+pub use score::{letter_grade, CategoryScore, ScoreReport};
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::check::{CheckCategory, CheckResult};
+use super::check::{CheckCategory, CheckResult, CheckStatus};
 
 /// Score for a specific category
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -20,12 +20,47 @@ impl CategoryScore {
         }
         (self.passed as f64 / self.total as f64) * 100.0
     }
+
+    /// Counts (passed, total) over results: warnings count as passes and
+    /// skipped checks are excluded from the total
+    pub fn tally(results: &[CheckResult]) -> (u32, u32) {
+        let passed = results
+            .iter()
+            .filter(|r| matches!(r.status, CheckStatus::Passed | CheckStatus::Warning))
+            .count() as u32;
+        let total = results
+            .iter()
+            .filter(|r| r.status != CheckStatus::Skipped)
+            .count() as u32;
+        (passed, total)
+    }
+}
+
+/// Letter grade of a percentage: A ≥90, B ≥80, C ≥70, D ≥50, F otherwise
+pub fn letter_grade(percentage: f64) -> char {
+    if percentage >= 90.0 {
+        'A'
+    } else if percentage >= 80.0 {
+        'B'
+    } else if percentage >= 70.0 {
+        'C'
+    } else if percentage >= 50.0 {
+        'D'
+    } else {
+        'F'
+    }
 }
 
 /// Complete score report for a repository
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScoreReport {
     pub repository: String,
+    /// Dominant language by bytes of code, from the GitHub languages API
+    #[serde(default)]
+    pub primary_language: Option<String>,
+    /// Analyzed branch, the repository's default one unless the user chose another
+    #[serde(default)]
+    pub branch: Option<String>,
     /// Total checks passed (Passed + Warning) across all categories
     pub passed: u32,
     /// Total evaluated checks (excludes Skipped) across all categories
@@ -54,6 +89,11 @@ impl ScoreReport {
         }
     }
 
+    /// Letter grade, on the same 90/50 boundaries as `grade_color`
+    pub fn letter_grade(&self) -> char {
+        letter_grade(self.percentage())
+    }
+
     pub fn grade_label(&self) -> &'static str {
         let pct = self.percentage();
         if pct >= 90.0 {
@@ -66,4 +106,238 @@ impl ScoreReport {
             "Insuffisant"
         }
     }
+
+    /// Swaps in a re-run check result in place and recomputes the category and
+    /// global scores. Returns false when the check is not part of the report.
+    pub fn replace_result(&mut self, result: CheckResult) -> bool {
+        let Some(slot) = self
+            .categories
+            .iter_mut()
+            .flat_map(|cat| cat.results.iter_mut())
+            .find(|r| r.check.id == result.check.id)
+        else {
+            return false;
+        };
+        *slot = result;
+
+        self.passed = 0;
+        self.total = 0;
+        for cat in &mut self.categories {
+            (cat.passed, cat.total) = CategoryScore::tally(&cat.results);
+            self.passed += cat.passed;
+            self.total += cat.total;
+        }
+        true
+    }
+
+    /// Renders the report as a Markdown summary to paste into PRs or wikis
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("# Rapport CI/CD — {}\n\n", self.repository);
+        md.push_str(&format!(
+            "**Score : {:.0}% ({}/{} checks) — {}**\n\n",
+            self.percentage(),
+            self.passed,
+            self.total,
+            self.grade_label()
+        ));
+
+        md.push_str("| Catégorie | Check | Statut |\n|-----------|-------|--------|\n");
+        for cat in &self.categories {
+            for result in &cat.results {
+                md.push_str(&format!(
+                    "| {} {} | {} | {} |\n",
+                    cat.category.icon(),
+                    cat.category.label(),
+                    escape_table_cell(&result.check.name),
+                    status_icon(&result.status)
+                ));
+            }
+        }
+
+        let failed: Vec<&CheckResult> = self
+            .categories
+            .iter()
+            .flat_map(|cat| &cat.results)
+            .filter(|r| r.status == CheckStatus::Failed)
+            .collect();
+        if !failed.is_empty() {
+            md.push_str("\n## Checks échoués\n\n");
+            for result in failed {
+                md.push_str(&format!("- **{}** : {}", result.check.name, result.detail));
+                if let Some(ref suggestion) = result.suggestion {
+                    md.push_str(&format!("\n  - 💡 {}", suggestion));
+                }
+                md.push('\n');
+            }
+        }
+
+        md
+    }
+
+    /// Renders a self-contained shields.io-style SVG badge of the score
+    pub fn to_badge_svg(&self) -> String {
+        let label = "CI/CD";
+        let value = format!("{:.0}%", self.percentage());
+        // Approximate Verdana 11px text widths, as shields.io does
+        let label_width = 10 + label.len() * 7;
+        let value_width = 10 + value.len() * 7;
+        let width = label_width + value_width;
+        let color = self.grade_color();
+
+        format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {value}"><title>{label}: {value}</title><linearGradient id="s" x2="0" y2="100%"><stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient><clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath><g clip-path="url(#r)"><rect width="{label_width}" height="20" fill="#555"/><rect x="{label_width}" width="{value_width}" height="20" fill="{color}"/><rect width="{width}" height="20" fill="url(#s)"/></g><g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11"><text x="{label_x}" y="14">{label}</text><text x="{value_x}" y="14">{value}</text></g></svg>"##,
+            label_x = label_width / 2,
+            value_x = label_width + value_width / 2,
+        )
+    }
+}
+
+/// Icon shown for a check status in exported summaries
+fn status_icon(status: &CheckStatus) -> &'static str {
+    match status {
+        CheckStatus::Passed => "✅",
+        CheckStatus::Failed => "❌",
+        CheckStatus::Warning => "⚠️",
+        CheckStatus::Skipped => "⏭️",
+    }
+}
+
+/// Escapes the characters that would break a Markdown table cell
+fn escape_table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Check;
+
+    fn result(id: &str, category: CheckCategory, status: CheckStatus) -> CheckResult {
+        let check = Check {
+            id: id.into(),
+            name: format!("Check {}", id),
+            description: String::new(),
+            category,
+        };
+        match status {
+            CheckStatus::Passed => CheckResult::passed(check, "ok"),
+            CheckStatus::Failed => CheckResult::failed(check, "ko", "Corrigez-le"),
+            CheckStatus::Warning => CheckResult::warning(check, "partiel", "Améliorez-le"),
+            CheckStatus::Skipped => CheckResult::skipped(check, "non applicable"),
+        }
+    }
+
+    fn sample_report() -> ScoreReport {
+        ScoreReport {
+            repository: "owner/repo".into(),
+            primary_language: Some("Rust".into()),
+            branch: Some("main".into()),
+            passed: 2,
+            total: 3,
+            categories: vec![
+                CategoryScore {
+                    category: CheckCategory::Pipeline,
+                    passed: 1,
+                    total: 2,
+                    results: vec![
+                        result("a", CheckCategory::Pipeline, CheckStatus::Passed),
+                        result("b", CheckCategory::Pipeline, CheckStatus::Failed),
+                    ],
+                },
+                CategoryScore {
+                    category: CheckCategory::Securite,
+                    passed: 1,
+                    total: 1,
+                    results: vec![
+                        result("c", CheckCategory::Securite, CheckStatus::Warning),
+                        result("d", CheckCategory::Securite, CheckStatus::Skipped),
+                    ],
+                },
+            ],
+            analyzed_at: "2024-05-17T09:30:00.000Z".into(),
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_contains_repo_and_score() {
+        let md = sample_report().to_markdown();
+        assert!(md.contains("owner/repo"));
+        assert!(md.contains("**Score : 67% (2/3 checks) — À améliorer**"));
+    }
+
+    #[test]
+    fn test_to_markdown_has_one_row_per_check() {
+        let md = sample_report().to_markdown();
+        let rows = md
+            .lines()
+            .filter(|l| l.starts_with("| ") && !l.starts_with("| Catégorie"))
+            .count();
+        assert_eq!(rows, 4);
+        assert!(md.contains("| 🔄 Pipeline CI | Check b | ❌ |"));
+    }
+
+    #[test]
+    fn test_to_markdown_lists_failed_checks_with_suggestion() {
+        let md = sample_report().to_markdown();
+        assert!(md.contains("## Checks échoués"));
+        assert!(md.contains("- **Check b** : ko\n  - 💡 Corrigez-le"));
+        assert!(!md.contains("- **Check c**"));
+    }
+
+    #[test]
+    fn test_badge_svg_contains_percentage_and_grade_color() {
+        let report = sample_report();
+        let svg = report.to_badge_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(">CI/CD</text>"));
+        assert!(svg.contains(">67%</text>"));
+        assert!(svg.contains(&format!("fill=\"{}\"", report.grade_color())));
+        assert_eq!(report.grade_color(), "#ffa400");
+    }
+
+    #[test]
+    fn test_replace_result_updates_scores_in_place() {
+        let mut report = sample_report();
+        let fixed = result("b", CheckCategory::Pipeline, CheckStatus::Passed);
+
+        assert!(report.replace_result(fixed.clone()));
+
+        assert_eq!(report.categories[0].results[1], fixed);
+        assert_eq!(report.categories[0].results.len(), 2);
+        assert_eq!(
+            (report.categories[0].passed, report.categories[0].total),
+            (2, 2)
+        );
+        assert_eq!((report.passed, report.total), (3, 3));
+    }
+
+    #[test]
+    fn test_replace_result_ignores_unknown_check() {
+        let mut report = sample_report();
+        let unknown = result("z", CheckCategory::Pipeline, CheckStatus::Passed);
+        assert!(!report.replace_result(unknown));
+        assert_eq!(report, sample_report());
+    }
+
+    #[test]
+    fn test_letter_grade_boundaries() {
+        let graded = |passed, total| {
+            ScoreReport {
+                passed,
+                total,
+                ..sample_report()
+            }
+            .letter_grade()
+        };
+        assert_eq!(graded(100, 100), 'A');
+        assert_eq!(graded(90, 100), 'A');
+        assert_eq!(graded(89, 100), 'B');
+        assert_eq!(graded(80, 100), 'B');
+        assert_eq!(graded(79, 100), 'C');
+        assert_eq!(graded(70, 100), 'C');
+        assert_eq!(graded(69, 100), 'D');
+        assert_eq!(graded(50, 100), 'D');
+        assert_eq!(graded(49, 100), 'F');
+        assert_eq!(graded(0, 0), 'F');
+    }
 }
@@ -1,4 +1,7 @@
-use gloo_net::http::{Request, RequestBuilder};
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gloo_net::http::{Request, RequestBuilder, Response};
 
 use super::types::*;
 
@@ -8,11 +11,49 @@ const GITHUB_API_BASE: &str = "https://api.github.com";
 #[derive(Debug, Clone)]
 pub struct GithubClient {
     token: Option<String>,
+    /// Shared between clones so the caller can read the quota left after an
+    /// analysis run on a clone
+    rate_limit: Rc<Cell<Option<RateLimit>>>,
 }
 
 impl GithubClient {
     pub fn new(token: Option<String>) -> Self {
-        Self { token }
+        Self {
+            token,
+            rate_limit: Rc::new(Cell::new(None)),
+        }
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    /// Quota reported by the most recent API response, if any
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit.get()
+    }
+
+    fn record_rate_limit(&self, remaining: Option<String>, limit: Option<String>) {
+        let parsed = remaining
+            .zip(limit)
+            .and_then(|(remaining, limit)| Some((remaining.parse().ok()?, limit.parse().ok()?)));
+        if let Some((remaining, limit)) = parsed {
+            self.rate_limit.set(Some(RateLimit { remaining, limit }));
+        }
+    }
+
+    /// Sends a request, recording the rate-limit headers of the response
+    async fn send(&self, url: &str) -> Result<Response, ApiError> {
+        let response = self.build_request(url).send().await.map_err(|e| ApiError {
+            status: 0,
+            message: format!("Network error: {}", e),
+        })?;
+        let headers = response.headers();
+        self.record_rate_limit(
+            headers.get("x-ratelimit-remaining"),
+            headers.get("x-ratelimit-limit"),
+        );
+        Ok(response)
     }
 
     /// Parse a GitHub URL into owner/repo
@@ -42,7 +83,11 @@ impl GithubClient {
             return Err("Owner and repo name cannot be empty".to_string());
         }
 
-        Ok(RepoIdentifier { owner, repo })
+        Ok(RepoIdentifier {
+            owner,
+            repo,
+            branch: None,
+        })
     }
 
     fn build_request(&self, url: &str) -> RequestBuilder {
@@ -58,10 +103,7 @@ impl GithubClient {
     }
 
     async fn fetch_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, ApiError> {
-        let response = self.build_request(url).send().await.map_err(|e| ApiError {
-            status: 0,
-            message: format!("Network error: {}", e),
-        })?;
+        let response = self.send(url).await?;
 
         let status = response.status();
         if status != 200 {
@@ -79,10 +121,7 @@ impl GithubClient {
     }
 
     async fn fetch_text(&self, url: &str) -> Result<String, ApiError> {
-        let response = self.build_request(url).send().await.map_err(|e| ApiError {
-            status: 0,
-            message: format!("Network error: {}", e),
-        })?;
+        let response = self.send(url).await?;
 
         let status = response.status();
         if status != 200 {
@@ -113,11 +152,8 @@ impl GithubClient {
         &self,
         repo: &RepoIdentifier,
     ) -> Result<Vec<GithubContent>, ApiError> {
-        let url = format!(
-            "{}/repos/{}/{}/contents/.github/workflows",
-            GITHUB_API_BASE, repo.owner, repo.repo
-        );
-        self.fetch_json(&url).await
+        self.fetch_json(&contents_url(repo, ".github/workflows"))
+            .await
     }
 
     /// Fetch a single file's content (base64 encoded)
@@ -126,11 +162,7 @@ impl GithubClient {
         repo: &RepoIdentifier,
         path: &str,
     ) -> Result<String, ApiError> {
-        let url = format!(
-            "{}/repos/{}/{}/contents/{}",
-            GITHUB_API_BASE, repo.owner, repo.repo, path
-        );
-        let content: GithubContent = self.fetch_json(&url).await?;
+        let content: GithubContent = self.fetch_json(&contents_url(repo, path)).await?;
 
         match content.content {
             Some(encoded) => {
@@ -160,23 +192,27 @@ impl GithubClient {
         path: &str,
     ) -> Result<String, ApiError> {
         let url = format!(
-            "https://raw.githubusercontent.com/{}/{}/HEAD/{}",
-            repo.owner, repo.repo, path
+            "https://raw.githubusercontent.com/{}/{}/{}/{}",
+            repo.owner,
+            repo.repo,
+            encode_ref(repo.git_ref()),
+            path
         );
         self.fetch_text(&url).await
     }
 
-    /// Fetch recent workflow runs
+    /// Fetch recent workflow runs of the analyzed branch, optionally only
+    /// those triggered by `event` (`push`, `pull_request`…) or with the given
+    /// `status` (`completed`, `success`…)
     pub async fn fetch_workflow_runs(
         &self,
         repo: &RepoIdentifier,
         per_page: u32,
+        event: Option<&str>,
+        status: Option<&str>,
     ) -> Result<WorkflowRunsResponse, ApiError> {
-        let url = format!(
-            "{}/repos/{}/{}/actions/runs?per_page={}&branch=main",
-            GITHUB_API_BASE, repo.owner, repo.repo, per_page
-        );
-        self.fetch_json(&url).await
+        self.fetch_json(&workflow_runs_url(repo, per_page, event, status))
+            .await
     }
 
     /// Check all workflow runs (not branch-filtered)
@@ -200,18 +236,64 @@ impl GithubClient {
     ) -> Result<BranchProtection, ApiError> {
         let url = format!(
             "{}/repos/{}/{}/branches/{}/protection",
-            GITHUB_API_BASE, repo.owner, repo.repo, branch
+            GITHUB_API_BASE,
+            repo.owner,
+            repo.repo,
+            encode_ref(branch)
         );
         self.fetch_json(&url).await
     }
 
-    /// Check if a file exists in the repo
-    pub async fn file_exists(&self, repo: &RepoIdentifier, path: &str) -> bool {
+    /// Fetch deployment environments and their protection rules
+    pub async fn fetch_environments(
+        &self,
+        repo: &RepoIdentifier,
+    ) -> Result<EnvironmentsResponse, ApiError> {
         let url = format!(
-            "{}/repos/{}/{}/contents/{}",
-            GITHUB_API_BASE, repo.owner, repo.repo, path
+            "{}/repos/{}/{}/environments",
+            GITHUB_API_BASE, repo.owner, repo.repo
         );
-        let response = self.build_request(&url).send().await;
+        self.fetch_json(&url).await
+    }
+
+    /// Fetch the repository languages, largest first (bytes of code)
+    pub async fn fetch_languages(
+        &self,
+        repo: &RepoIdentifier,
+    ) -> Result<Vec<(String, u64)>, ApiError> {
+        let url = format!(
+            "{}/repos/{}/{}/languages",
+            GITHUB_API_BASE, repo.owner, repo.repo
+        );
+        let languages: LanguagesResponse = self.fetch_json(&url).await?;
+        Ok(languages_by_size(languages))
+    }
+
+    /// Check that the token is accepted and return its OAuth scopes. Fine-grained
+    /// tokens report no scopes, so an empty list does not mean "no access".
+    pub async fn validate_token(&self) -> Result<Vec<String>, ApiError> {
+        let url = format!("{}/user", GITHUB_API_BASE);
+        let response = self.send(&url).await?;
+
+        let status = response.status();
+        if status != 200 {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ApiError {
+                status,
+                message: format!("HTTP {}: {}", status, body),
+            });
+        }
+
+        Ok(response
+            .headers()
+            .get("x-oauth-scopes")
+            .map(|header| parse_scopes_header(&header))
+            .unwrap_or_default())
+    }
+
+    /// Check if a file exists in the repo
+    pub async fn file_exists(&self, repo: &RepoIdentifier, path: &str) -> bool {
+        let response = self.send(&contents_url(repo, path)).await;
         matches!(response, Ok(r) if r.status() == 200)
     }
 
@@ -223,7 +305,10 @@ impl GithubClient {
     ) -> Result<TreeResponse, ApiError> {
         let url = format!(
             "{}/repos/{}/{}/git/trees/{}?recursive=1",
-            GITHUB_API_BASE, repo.owner, repo.repo, branch
+            GITHUB_API_BASE,
+            repo.owner,
+            repo.repo,
+            encode_ref(branch)
         );
         self.fetch_json(&url).await
     }
@@ -241,20 +326,93 @@ impl GithubClient {
         self.fetch_json(&url).await
     }
 
-    /// Fetch recent commits from main branch
+    /// Fetch recent commits of the analyzed branch
     pub async fn fetch_commits(
         &self,
         repo: &RepoIdentifier,
         per_page: u32,
     ) -> Result<Vec<CommitItem>, ApiError> {
         let url = format!(
-            "{}/repos/{}/{}/commits?sha=main&per_page={}",
-            GITHUB_API_BASE, repo.owner, repo.repo, per_page
+            "{}/repos/{}/{}/commits?sha={}&per_page={}",
+            GITHUB_API_BASE,
+            repo.owner,
+            repo.repo,
+            encode_ref(repo.git_ref()),
+            per_page
         );
         self.fetch_json(&url).await
     }
 }
 
+/// Percent-encodes a git ref for use in a URL path segment or query value.
+/// Slashes are kept: `feature/login` is how GitHub expects nested branches.
+fn encode_ref(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Contents API URL of a path, pinned to the analyzed branch when one is set
+fn contents_url(repo: &RepoIdentifier, path: &str) -> String {
+    let url = format!(
+        "{}/repos/{}/{}/contents/{}",
+        GITHUB_API_BASE, repo.owner, repo.repo, path
+    );
+    match repo.branch {
+        Some(ref branch) => format!("{}?ref={}", url, encode_ref(branch)),
+        None => url,
+    }
+}
+
+/// Workflow runs URL, filtered on the analyzed branch when one is set and on
+/// the triggering event and run status when given
+fn workflow_runs_url(
+    repo: &RepoIdentifier,
+    per_page: u32,
+    event: Option<&str>,
+    status: Option<&str>,
+) -> String {
+    let mut url = format!(
+        "{}/repos/{}/{}/actions/runs?per_page={}",
+        GITHUB_API_BASE, repo.owner, repo.repo, per_page
+    );
+    let filters = [
+        ("branch", repo.branch.as_deref()),
+        ("event", event),
+        ("status", status),
+    ];
+    for (name, value) in filters {
+        if let Some(value) = value {
+            url.push_str(&format!("&{}={}", name, encode_ref(value)));
+        }
+    }
+    url
+}
+
+/// Orders languages by decreasing size, then by name for equal sizes
+fn languages_by_size(languages: LanguagesResponse) -> Vec<(String, u64)> {
+    let mut languages: Vec<(String, u64)> = languages.into_iter().collect();
+    languages.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    languages
+}
+
+/// Splits an `X-OAuth-Scopes` header ("repo, read:org") into scope names
+fn parse_scopes_header(header: &str) -> Vec<String> {
+    header
+        .split(',')
+        .map(str::trim)
+        .filter(|scope| !scope.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -284,4 +442,91 @@ mod tests {
     fn test_parse_invalid_url() {
         assert!(GithubClient::parse_repo_url("not-a-url").is_err());
     }
+
+    #[test]
+    fn test_parse_scopes_header() {
+        assert_eq!(
+            parse_scopes_header("repo, read:org,workflow"),
+            vec!["repo", "read:org", "workflow"]
+        );
+        assert!(parse_scopes_header("").is_empty());
+    }
+
+    #[test]
+    fn test_rate_limit_recorded_from_headers() {
+        let client = GithubClient::new(None);
+        assert_eq!(client.rate_limit(), None);
+
+        let shared = client.clone();
+        client.record_rate_limit(Some("43".into()), Some("60".into()));
+        assert_eq!(
+            shared.rate_limit(),
+            Some(RateLimit {
+                remaining: 43,
+                limit: 60
+            })
+        );
+
+        // Responses without usable headers keep the last known quota
+        client.record_rate_limit(None, Some("60".into()));
+        client.record_rate_limit(Some("n/a".into()), Some("60".into()));
+        assert_eq!(shared.rate_limit().map(|r| r.remaining), Some(43));
+    }
+
+    #[test]
+    fn test_languages_by_size() {
+        let languages: LanguagesResponse =
+            serde_json::from_str(r#"{ "CSS": 1200, "Rust": 84210, "HTML": 1200, "Shell": 90 }"#)
+                .unwrap();
+        let ordered = languages_by_size(languages);
+        let names: Vec<&str> = ordered.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["Rust", "CSS", "HTML", "Shell"]);
+        assert_eq!(ordered[0].1, 84210);
+    }
+
+    #[test]
+    fn test_branch_propagates_into_urls() {
+        let mut repo = GithubClient::parse_repo_url("owner/repo").unwrap();
+        assert_eq!(
+            workflow_runs_url(&repo, 5, None, None),
+            "https://api.github.com/repos/owner/repo/actions/runs?per_page=5"
+        );
+
+        repo.branch = Some("develop".into());
+        assert_eq!(
+            workflow_runs_url(&repo, 5, None, None),
+            "https://api.github.com/repos/owner/repo/actions/runs?per_page=5&branch=develop"
+        );
+        assert_eq!(
+            contents_url(&repo, ".github/workflows"),
+            "https://api.github.com/repos/owner/repo/contents/.github/workflows?ref=develop"
+        );
+        assert_eq!(repo.git_ref(), "develop");
+    }
+
+    #[test]
+    fn test_branch_is_percent_encoded_in_urls() {
+        let mut repo = GithubClient::parse_repo_url("owner/repo").unwrap();
+        repo.branch = Some("feat/a+b#1&c%".into());
+        assert_eq!(
+            contents_url(&repo, "README.md"),
+            "https://api.github.com/repos/owner/repo/contents/README.md?ref=feat/a%2Bb%231%26c%25"
+        );
+        assert!(workflow_runs_url(&repo, 5, Some("push"), None)
+            .ends_with("&branch=feat/a%2Bb%231%26c%25&event=push"));
+        assert_eq!(encode_ref("main"), "main");
+        assert_eq!(encode_ref("fix été"), "fix%20%C3%A9t%C3%A9");
+    }
+
+    #[test]
+    fn test_workflow_runs_url_event_and_status_filters() {
+        let mut repo = GithubClient::parse_repo_url("owner/repo").unwrap();
+        repo.branch = Some("main".into());
+        assert_eq!(
+            workflow_runs_url(&repo, 1, Some("push"), Some("completed")),
+            "https://api.github.com/repos/owner/repo/actions/runs?per_page=1&branch=main&event=push&status=completed"
+        );
+        assert!(workflow_runs_url(&repo, 1, Some("pull_request"), None)
+            .ends_with("&event=pull_request"));
+    }
 }
@@ -0,0 +1,88 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+use crate::models::ScoreReport;
+
+/// Builds the download file name of a report: repo slug + analysis date
+pub fn report_filename(report: &ScoreReport, extension: &str) -> String {
+    let slug = report.repository.replace('/', "-");
+    let date = report.analyzed_at.get(..10).unwrap_or("report");
+    format!("cicd-report-{}-{}.{}", slug, date, extension)
+}
+
+/// Serializes a report to pretty-printed JSON
+pub fn report_to_json(report: &ScoreReport) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(report)
+}
+
+/// Triggers a browser download of `contents` through a Blob object URL
+pub fn download_file(filename: &str, contents: &str, mime_type: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("Document indisponible"))?;
+    let anchor: HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    Url::revoke_object_url(&url)
+}
+
+/// Copies text to the clipboard through the asynchronous Clipboard API
+pub fn copy_to_clipboard(text: &str) -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or_else(|| JsValue::from_str("Fenêtre indisponible"))?;
+    // The returned promise only rejects on permission denial, nothing to recover
+    let _ = window.navigator().clipboard().write_text(text);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CategoryScore, Check, CheckCategory, CheckResult};
+
+    fn sample_report() -> ScoreReport {
+        let check = Check {
+            id: "readme_exists".into(),
+            name: "README présent".into(),
+            description: "Fichier README.md à la racine".into(),
+            category: CheckCategory::BonnesPratiques,
+        };
+        ScoreReport {
+            repository: "owner/repo".into(),
+            primary_language: None,
+            branch: None,
+            passed: 1,
+            total: 1,
+            categories: vec![CategoryScore {
+                category: CheckCategory::BonnesPratiques,
+                passed: 1,
+                total: 1,
+                results: vec![CheckResult::passed(check, "Fichier README.md trouvé")],
+            }],
+            analyzed_at: "2024-05-17T09:30:00.000Z".into(),
+        }
+    }
+
+    #[test]
+    fn test_report_filename_has_slug_and_date() {
+        assert_eq!(
+            report_filename(&sample_report(), "json"),
+            "cicd-report-owner-repo-2024-05-17.json"
+        );
+    }
+
+    #[test]
+    fn test_report_json_round_trip() {
+        let report = sample_report();
+        let json = report_to_json(&report).unwrap();
+        let parsed: ScoreReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, report);
+    }
+}
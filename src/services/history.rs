@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ScoreReport;
+
+const STORAGE_KEY: &str = "analysis-history";
+
+/// Maximum number of analyses kept, most recent first
+pub const MAX_HISTORY_ENTRIES: usize = 10;
+
+/// Lightweight record of a past analysis
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub repo: String,
+    /// Analyzed branch, replayed when the entry is re-analyzed (absent from
+    /// entries stored by older versions)
+    #[serde(default)]
+    pub branch: Option<String>,
+    pub percentage: f64,
+    pub analyzed_at: String,
+}
+
+impl HistoryEntry {
+    pub fn from_report(report: &ScoreReport) -> Self {
+        Self {
+            repo: report.repository.clone(),
+            branch: report.branch.clone(),
+            percentage: report.percentage(),
+            analyzed_at: report.analyzed_at.clone(),
+        }
+    }
+}
+
+/// Puts `entry` first, drops any older entry of the same repo and branch and
+/// caps the list
+pub fn push_entry(entries: &mut Vec<HistoryEntry>, entry: HistoryEntry) {
+    entries.retain(|e| e.repo != entry.repo || e.branch != entry.branch);
+    entries.insert(0, entry);
+    entries.truncate(MAX_HISTORY_ENTRIES);
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+/// Lists stored analyses, most recent first (empty when storage is unavailable)
+pub fn list() -> Vec<HistoryEntry> {
+    storage()
+        .and_then(|s| s.get_item(STORAGE_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Records a completed analysis and returns the updated history
+pub fn add(report: &ScoreReport) -> Vec<HistoryEntry> {
+    let mut entries = list();
+    push_entry(&mut entries, HistoryEntry::from_report(report));
+    if let (Some(storage), Ok(json)) = (storage(), serde_json::to_string(&entries)) {
+        let _ = storage.set_item(STORAGE_KEY, &json);
+    }
+    entries
+}
+
+/// Forgets every stored analysis
+pub fn clear() {
+    if let Some(storage) = storage() {
+        let _ = storage.remove_item(STORAGE_KEY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(repo: &str) -> HistoryEntry {
+        HistoryEntry {
+            repo: repo.to_string(),
+            branch: None,
+            percentage: 50.0,
+            analyzed_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_push_entry_keeps_most_recent_first() {
+        let mut entries = Vec::new();
+        push_entry(&mut entries, entry("a/one"));
+        push_entry(&mut entries, entry("a/two"));
+        let repos: Vec<&str> = entries.iter().map(|e| e.repo.as_str()).collect();
+        assert_eq!(repos, vec!["a/two", "a/one"]);
+    }
+
+    #[test]
+    fn test_push_entry_caps_history() {
+        let mut entries = Vec::new();
+        for i in 0..15 {
+            push_entry(&mut entries, entry(&format!("owner/repo{}", i)));
+        }
+        assert_eq!(entries.len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(entries[0].repo, "owner/repo14");
+        assert_eq!(entries[MAX_HISTORY_ENTRIES - 1].repo, "owner/repo5");
+    }
+
+    #[test]
+    fn test_push_entry_moves_reanalyzed_repo_to_front() {
+        let mut entries = Vec::new();
+        push_entry(&mut entries, entry("a/one"));
+        push_entry(&mut entries, entry("a/two"));
+        push_entry(&mut entries, entry("a/one"));
+        let repos: Vec<&str> = entries.iter().map(|e| e.repo.as_str()).collect();
+        assert_eq!(repos, vec!["a/one", "a/two"]);
+    }
+
+    #[test]
+    fn test_push_entry_keeps_each_branch() {
+        let mut entries = Vec::new();
+        push_entry(&mut entries, entry("a/one"));
+        push_entry(
+            &mut entries,
+            HistoryEntry {
+                branch: Some("develop".into()),
+                ..entry("a/one")
+            },
+        );
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].branch.as_deref(), Some("develop"));
+    }
+
+    #[test]
+    fn test_entry_without_branch_deserializes() {
+        let entry: HistoryEntry = serde_json::from_str(
+            r#"{"repo":"a/one","percentage":80.0,"analyzed_at":"2026-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(entry.branch, None);
+    }
+}
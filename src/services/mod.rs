@@ -1,4 +1,6 @@
 mod client;
+pub mod export;
+pub mod history;
 mod types;
 
 pub use client::GithubClient;
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 /// Parsed owner/repo from a GitHub URL
@@ -5,12 +7,19 @@ use serde::Deserialize;
 pub struct RepoIdentifier {
     pub owner: String,
     pub repo: String,
+    /// Branch to analyze; `None` means the default branch
+    pub branch: Option<String>,
 }
 
 impl RepoIdentifier {
     pub fn full_name(&self) -> String {
         format!("{}/{}", self.owner, self.repo)
     }
+
+    /// Git ref used in tree, commit and raw-file queries
+    pub fn git_ref(&self) -> &str {
+        self.branch.as_deref().unwrap_or("HEAD")
+    }
 }
 
 /// GitHub workflow file representation
@@ -36,6 +45,9 @@ pub struct WorkflowRun {
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
     pub run_started_at: Option<String>,
+    /// 1 for the first attempt, incremented on each "Re-run"
+    #[serde(default)]
+    pub run_attempt: Option<u32>,
 }
 
 /// Response wrapper for workflow runs
@@ -48,9 +60,20 @@ pub struct WorkflowRunsResponse {
 /// Branch protection rules
 #[derive(Debug, Clone, Deserialize)]
 pub struct BranchProtection {
-    pub required_pull_request_reviews: Option<serde_json::Value>,
+    pub required_pull_request_reviews: Option<RequiredPullRequestReviews>,
     pub enforce_admins: Option<EnforceAdmins>,
     pub required_status_checks: Option<serde_json::Value>,
+    #[serde(default)]
+    pub required_linear_history: Option<RequiredLinearHistory>,
+}
+
+/// Pull request review requirements of a branch protection
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequiredPullRequestReviews {
+    #[serde(default)]
+    pub required_approving_review_count: u32,
+    #[serde(default)]
+    pub require_code_owner_reviews: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -58,6 +81,36 @@ pub struct EnforceAdmins {
     pub enabled: bool,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequiredLinearHistory {
+    pub enabled: bool,
+}
+
+/// Deployment environments of a repository
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnvironmentsResponse {
+    pub total_count: u32,
+    #[serde(default)]
+    pub environments: Vec<Environment>,
+}
+
+/// Deployment environment
+#[derive(Debug, Clone, Deserialize)]
+pub struct Environment {
+    pub name: String,
+    #[serde(default)]
+    pub protection_rules: Vec<ProtectionRule>,
+}
+
+/// Environment protection rule (`wait_timer`, `required_reviewers`, `branch_policy`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProtectionRule {
+    #[serde(rename = "type")]
+    pub rule_type: String,
+    #[serde(default)]
+    pub wait_timer: Option<u32>,
+}
+
 /// Repository metadata
 #[derive(Debug, Clone, Deserialize)]
 pub struct RepoMetadata {
@@ -67,6 +120,15 @@ pub struct RepoMetadata {
     pub private: bool,
     #[serde(default)]
     pub description: Option<String>,
+    #[serde(default)]
+    pub license: Option<RepoLicense>,
+}
+
+/// License detected by GitHub for a repository
+#[derive(Debug, Clone, Deserialize)]
+pub struct RepoLicense {
+    /// SPDX identifier ("MIT", "Apache-2.0", or "NOASSERTION" when unrecognized)
+    pub spdx_id: Option<String>,
 }
 
 /// Tree entry (for recursive file listing)
@@ -105,8 +167,27 @@ pub struct CommitItem {
 #[derive(Debug, Clone, Deserialize)]
 pub struct CommitDetail {
     pub message: String,
+    #[serde(default)]
+    pub verification: Option<CommitVerification>,
 }
 
+/// Commit signature verification status
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommitVerification {
+    pub verified: bool,
+    pub reason: String,
+}
+
+/// Last-seen GitHub API quota, from the `X-RateLimit-*` response headers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub remaining: u32,
+    pub limit: u32,
+}
+
+/// Languages of a repository: language name → bytes of code
+pub type LanguagesResponse = HashMap<String, u64>;
+
 /// API error
 #[derive(Debug, Clone)]
 pub struct ApiError {
@@ -119,3 +200,165 @@ impl std::fmt::Display for ApiError {
         write!(f, "GitHub API error {}: {}", self.status, self.message)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repo_metadata_license_spdx() {
+        let json = r#"{
+            "name": "rust",
+            "full_name": "rust-lang/rust",
+            "default_branch": "master",
+            "private": false,
+            "license": { "key": "apache-2.0", "name": "Apache License 2.0", "spdx_id": "Apache-2.0" }
+        }"#;
+        let meta: RepoMetadata = serde_json::from_str(json).unwrap();
+        assert_eq!(meta.license.unwrap().spdx_id.as_deref(), Some("Apache-2.0"));
+    }
+
+    #[test]
+    fn test_repo_metadata_without_license() {
+        let json = r#"{
+            "name": "repo",
+            "full_name": "owner/repo",
+            "default_branch": "main",
+            "private": false,
+            "license": null
+        }"#;
+        let meta: RepoMetadata = serde_json::from_str(json).unwrap();
+        assert!(meta.license.is_none());
+    }
+
+    #[test]
+    fn test_commit_verification() {
+        let json = r#"[
+            {
+                "sha": "a1",
+                "commit": {
+                    "message": "feat: signed",
+                    "verification": { "verified": true, "reason": "valid", "signature": null, "payload": null }
+                }
+            },
+            { "sha": "b2", "commit": { "message": "fix: legacy payload" } }
+        ]"#;
+        let commits: Vec<CommitItem> = serde_json::from_str(json).unwrap();
+        let verification = commits[0].commit.verification.as_ref().unwrap();
+        assert!(verification.verified);
+        assert_eq!(verification.reason, "valid");
+        assert!(commits[1].commit.verification.is_none());
+    }
+
+    #[test]
+    fn test_environments_protection_rules() {
+        let json = r#"{
+            "total_count": 2,
+            "environments": [
+                {
+                    "id": 1,
+                    "name": "production",
+                    "protection_rules": [
+                        { "id": 10, "type": "wait_timer", "wait_timer": 30 },
+                        { "id": 11, "type": "required_reviewers", "reviewers": [] }
+                    ]
+                },
+                { "id": 2, "name": "staging" }
+            ]
+        }"#;
+        let response: EnvironmentsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.environments.len(), 2);
+        let prod = &response.environments[0];
+        assert_eq!(prod.protection_rules[0].rule_type, "wait_timer");
+        assert_eq!(prod.protection_rules[0].wait_timer, Some(30));
+        assert_eq!(prod.protection_rules[1].wait_timer, None);
+        assert!(response.environments[1].protection_rules.is_empty());
+    }
+
+    #[test]
+    fn test_branch_protection_linear_history() {
+        let json = r#"{
+            "enforce_admins": { "enabled": true },
+            "required_linear_history": { "enabled": true },
+            "allow_force_pushes": { "enabled": false }
+        }"#;
+        let protection: BranchProtection = serde_json::from_str(json).unwrap();
+        assert!(protection.required_linear_history.unwrap().enabled);
+
+        let protection: BranchProtection = serde_json::from_str("{}").unwrap();
+        assert!(protection.required_linear_history.is_none());
+    }
+
+    #[test]
+    fn test_environments_required_reviewers() {
+        let json = r#"{
+            "total_count": 1,
+            "environments": [
+                {
+                    "id": 161088068,
+                    "node_id": "MDExOkVudmlyb25tZW50MTYxMDg4MDY4",
+                    "name": "staging",
+                    "url": "https://api.github.com/repos/github/hello-world/environments/staging",
+                    "html_url": "https://github.com/github/hello-world/deployments/activity_log?environments_filter=staging",
+                    "created_at": "2020-11-23T22:00:40Z",
+                    "updated_at": "2020-11-23T22:00:40Z",
+                    "protection_rules": [
+                        {
+                            "id": 3736,
+                            "node_id": "MDQ6R2F0ZTM3MzY=",
+                            "type": "required_reviewers",
+                            "prevent_self_review": false,
+                            "reviewers": [{ "type": "User", "reviewer": { "login": "octocat" } }]
+                        },
+                        { "id": 3755, "node_id": "MDQ6R2F0ZTM3NTU=", "type": "branch_policy" }
+                    ],
+                    "deployment_branch_policy": { "protected_branches": false, "custom_branch_policies": true }
+                }
+            ]
+        }"#;
+        let response: EnvironmentsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.total_count, 1);
+        let rules = &response.environments[0].protection_rules;
+        assert_eq!(rules[0].rule_type, "required_reviewers");
+        assert_eq!(rules[1].rule_type, "branch_policy");
+    }
+
+    #[test]
+    fn test_workflow_run_attempt() {
+        let json = r#"{
+            "total_count": 2,
+            "workflow_runs": [
+                { "id": 1, "name": "CI", "status": "completed", "conclusion": "success", "run_attempt": 3 },
+                { "id": 2, "name": "CI", "status": "completed", "conclusion": "failure" }
+            ]
+        }"#;
+        let runs: WorkflowRunsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(runs.workflow_runs[0].run_attempt, Some(3));
+        assert_eq!(runs.workflow_runs[1].run_attempt, None);
+    }
+
+    #[test]
+    fn test_branch_protection_code_owner_reviews() {
+        let json = r#"{
+            "required_pull_request_reviews": {
+                "dismiss_stale_reviews": true,
+                "require_code_owner_reviews": true,
+                "required_approving_review_count": 2
+            }
+        }"#;
+        let protection: BranchProtection = serde_json::from_str(json).unwrap();
+        let reviews = protection.required_pull_request_reviews.unwrap();
+        assert!(reviews.require_code_owner_reviews);
+        assert_eq!(reviews.required_approving_review_count, 2);
+
+        let json =
+            r#"{ "required_pull_request_reviews": { "required_approving_review_count": 1 } }"#;
+        let protection: BranchProtection = serde_json::from_str(json).unwrap();
+        assert!(
+            !protection
+                .required_pull_request_reviews
+                .unwrap()
+                .require_code_owner_reviews
+        );
+    }
+}